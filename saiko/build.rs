@@ -1,16 +1,20 @@
 extern crate wayland_protocol_code_generator;
 
+use std::env;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
-use std::process::Command;
 
 fn main() {
-    let dest_path = Path::new("./src/").join("wayland.rs");
-    let mut file = File::create(&dest_path).unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
 
+    let mut file = File::create(Path::new(&out_dir).join("wayland.rs")).unwrap();
     file.write_all(wayland_protocol_code_generator::generate_wayland_protocol_code().as_bytes())
         .unwrap();
 
-    Command::new("cargo").arg("fmt").spawn().unwrap();
+    let mut file = File::create(Path::new(&out_dir).join("wayland_server.rs")).unwrap();
+    file.write_all(wayland_protocol_code_generator::generate_wayland_server_code().as_bytes())
+        .unwrap();
+
+    println!("cargo:rerun-if-changed=build.rs");
 }
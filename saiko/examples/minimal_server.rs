@@ -0,0 +1,77 @@
+extern crate env_logger;
+#[macro_use]
+extern crate log;
+extern crate saiko;
+
+use saiko::error::WireError;
+use saiko::server::Connection;
+use saiko::socket::WaylandSocket;
+use saiko::wayland_server::*;
+use std::os::unix::io::{FromRawFd, IntoRawFd};
+use std::os::unix::net::UnixListener;
+use std::thread;
+
+// Announces a wl_shm global and answers the requests every client starts with.
+fn serve(connection: Connection) {
+    loop {
+        let requests = match connection.read_requests() {
+            Ok(requests) => requests,
+            Err(WireError::Disconnected) => {
+                info!("Client disconnected");
+                return;
+            }
+            Err(err) => {
+                error!("Protocol error: {}", err);
+                return;
+            }
+        };
+        for request in requests {
+            match request {
+                Request::WlDisplayRequest(WlDisplayRequest::WlDisplaysyncRequest(sync)) => {
+                    sync.callback.done(0);
+                    // wl_callback.done is not marked as a destructor in this
+                    // version of the protocol, the callback is gone anyway.
+                    connection.destroy_resource(sync.callback.object_id());
+                }
+                Request::WlDisplayRequest(WlDisplayRequest::WlDisplaygetRegistryRequest(
+                    get_registry,
+                )) => {
+                    get_registry
+                        .registry
+                        .global(1, WlShm::NAME.to_string(), WlShm::VERSION);
+                }
+                Request::WlRegistryRequest(WlRegistryRequest::WlRegistrybindRequest(bind)) => {
+                    if bind.name == 1 && bind.id.interface == WlShm::NAME {
+                        let wl_shm =
+                            connection.create_resource::<WlShm>(bind.id.id, bind.id.version);
+                        wl_shm.format(WlShmFormat::Argb8888);
+                        wl_shm.format(WlShmFormat::Xrgb8888);
+                    } else {
+                        connection.get_display().error(
+                            bind.sender_id,
+                            WlDisplayError::InvalidObject.into(),
+                            format!("no global {}", bind.name),
+                        );
+                    }
+                }
+                _ => info!("Unhandled request"),
+            }
+        }
+    }
+}
+
+fn main() {
+    env_logger::init();
+
+    let path =
+        std::path::Path::new(&std::env::var("XDG_RUNTIME_DIR").unwrap()).join("saiko-server-0");
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path).unwrap();
+    println!("Listening on {}", path.display());
+
+    for stream in listener.incoming() {
+        let socket = unsafe { WaylandSocket::from_raw_fd(stream.unwrap().into_raw_fd()) };
+        let connection = Connection::new(socket);
+        thread::spawn(move || serve(connection));
+    }
+}
@@ -0,0 +1,80 @@
+extern crate env_logger;
+#[macro_use]
+extern crate log;
+extern crate saiko;
+
+use saiko::types::Fixed;
+use saiko::wayland;
+use saiko::wayland::*;
+use std::sync::{Arc, Mutex};
+
+saiko::protocol!("examples/protocols/viewporter.xml");
+
+use viewporter::WpViewporter;
+
+fn main() {
+    env_logger::init();
+
+    let client = saiko::client::Client::connect(None);
+
+    let wl_compositor = Arc::new(Mutex::new(None));
+    let c_wl_compositor = wl_compositor.clone();
+
+    let wp_viewporter = Arc::new(Mutex::new(None));
+    let c_wp_viewporter = wp_viewporter.clone();
+
+    let c_client = client.clone();
+    client.add_event_listener(Box::new(move |event| {
+        if let wayland::Event::WlRegistryEvent(wayland::WlRegistryEvent::WlRegistryglobalEvent(
+            gl_ev,
+        )) = event
+        {
+            let wl_registry = c_client
+                .get_obj(gl_ev.sender_id)
+                .unwrap()
+                .try_get_wl_registry()
+                .unwrap();
+
+            if gl_ev.interface == WlCompositor::NAME {
+                let version = std::cmp::min(gl_ev.version, WlCompositor::VERSION);
+                *c_wl_compositor.lock().unwrap() =
+                    Some(wl_registry.bind::<WlCompositor>(gl_ev.name, version));
+            } else if gl_ev.interface == WpViewporter::NAME {
+                let version = std::cmp::min(gl_ev.version, WpViewporter::VERSION);
+                *c_wp_viewporter.lock().unwrap() =
+                    Some(wl_registry.bind::<WpViewporter>(gl_ev.name, version));
+            }
+        }
+    }));
+
+    client.get_display().get_registry();
+    client.sync();
+
+    let wl_compositor = wl_compositor.lock().unwrap().take().unwrap();
+    let wp_viewporter = match wp_viewporter.lock().unwrap().take() {
+        Some(wp_viewporter) => wp_viewporter,
+        None => {
+            println!("The compositor does not support wp_viewporter");
+            return;
+        }
+    };
+
+    let wl_surface = wl_compositor.create_surface();
+    let wp_viewport = wp_viewporter.get_viewport(&wl_surface);
+    wp_viewport.set_source(
+        Fixed::from_int(0),
+        Fixed::from_int(0),
+        Fixed::from_f64(240.5),
+        Fixed::from_f64(180.5),
+    );
+    wp_viewport.set_destination(480, 360);
+    wl_surface.commit();
+    client.sync();
+    info!("Viewport {} set up", wp_viewport.object_id);
+
+    wp_viewport.destroy();
+    wp_viewporter.destroy();
+    client.sync();
+
+    client.disconnect();
+}
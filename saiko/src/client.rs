@@ -1,18 +1,23 @@
+use super::error::WireError;
 use super::socket::WaylandSocket;
 use super::wayland;
 use super::wayland::{WlDisplay, WlObject, WlRawObject};
-use crate::wayland::WlCallback;
-use std::collections::HashMap;
+use super::wire::SERVER_ID_START;
+use std::collections::{HashMap, HashSet};
 use std::sync::Mutex;
 use std::sync::{Arc, Condvar, RwLock};
 use std::thread;
 
+pub type EventListener = Box<dyn Fn(&wayland::Event) + Send + Sync>;
+
 #[derive(Clone)]
 pub struct Client {
-    socket: Arc<WaylandSocket>,
+    pub(crate) socket: Arc<WaylandSocket>,
     pub obj_map: Arc<Mutex<HashMap<u32, Arc<WlObject>>>>,
     pub max_id: Arc<Mutex<u32>>,
-    pub event_listeners: Arc<RwLock<Vec<Box<Fn(&wayland::Event) + Send + Sync>>>>,
+    zombies: Arc<Mutex<HashSet<u32>>>,
+    free_ids: Arc<Mutex<Vec<u32>>>,
+    pub event_listeners: Arc<RwLock<Vec<EventListener>>>,
 }
 
 impl Client {
@@ -23,25 +28,76 @@ impl Client {
             socket,
             obj_map: Arc::new(Mutex::new(HashMap::new())),
             max_id: Arc::new(Mutex::new(0)),
+            zombies: Arc::new(Mutex::new(HashSet::new())),
+            free_ids: Arc::new(Mutex::new(Vec::new())),
             event_listeners: Arc::new(RwLock::new(Vec::new())),
         };
-        client.bind_obj::<WlDisplay>(1);
+        client.bind_obj::<WlDisplay>(1, 1);
         client.start_event_loop();
 
-        return client;
+        client
     }
 
     pub fn start_event_loop(&self) {
         let this = self.clone();
-        thread::spawn(move || loop {
-            let evs = this.socket.read_event();
+        thread::spawn(move || 'events: loop {
+            // A malformed header leaves no way to find the next message.
+            let evs = match this.socket.read_event() {
+                Ok(evs) => evs,
+                Err(WireError::Disconnected) => {
+                    info!("Disconnected from the compositor");
+                    break;
+                }
+                Err(err) => {
+                    error!("Failed to read events: {}", err);
+                    break;
+                }
+            };
             for (raw_event_header, msg_body) in evs {
-                let sender = this.get_obj(raw_event_header.sender_id).unwrap();
-                let event = sender.parse_event(
+                let received_fds = this.socket.received_fds().len();
+                let sender = match this.get_obj(raw_event_header.sender_id) {
+                    Some(sender) => sender,
+                    // Sent before the server handled the destructor of one of
+                    // its own objects.
+                    None if raw_event_header.sender_id >= SERVER_ID_START => {
+                        info!(
+                            "Dropping event {} of destroyed object {}",
+                            raw_event_header.opcode, raw_event_header.sender_id
+                        );
+                        continue;
+                    }
+                    None => {
+                        let err = WireError::UnknownObject(raw_event_header.sender_id);
+                        error!("Failed to dispatch event: {}", err);
+                        break 'events;
+                    }
+                };
+                let event = match sender.parse_event(
                     raw_event_header.sender_id,
-                    raw_event_header.op_code,
-                    msg_body,
-                );
+                    raw_event_header.opcode,
+                    &msg_body,
+                ) {
+                    Ok(event) => event,
+                    Err(err) => {
+                        error!(
+                            "Failed to parse event {} of object {}: {}",
+                            raw_event_header.opcode, raw_event_header.sender_id, err
+                        );
+                        this.drop_unread_fds(&sender, raw_event_header.opcode, received_fds);
+                        continue;
+                    }
+                };
+                if let wayland::Event::WlDisplayEvent(
+                    wayland::WlDisplayEvent::WlDisplaydeleteIdEvent(delete_id),
+                ) = &event
+                {
+                    this.delete_obj(delete_id.id);
+                }
+                // Events already on their way to a destroyed object are parsed, so
+                // that any fds they carry get closed, but never dispatched.
+                if this.is_zombie(raw_event_header.sender_id) {
+                    continue;
+                }
                 for event_handler in this.event_listeners.read().unwrap().iter() {
                     event_handler(&event);
                 }
@@ -49,32 +105,57 @@ impl Client {
         });
     }
 
+    // Fds are only taken from the queue once the parser reaches them, those of
+    // an event that failed to parse before would end up in the next event that
+    // carries fds.
+    fn drop_unread_fds(&self, sender: &WlObject, opcode: u16, received_fds: usize) {
+        let event_fds = match sender.descriptor().event(opcode) {
+            Some(event) => event.signature.matches('h').count(),
+            None => return,
+        };
+        let mut fds = self.socket.received_fds();
+        let taken = received_fds.saturating_sub(fds.len());
+        for _ in taken..event_fds {
+            fds.pop_front();
+        }
+    }
+
+    pub fn socket(&self) -> &WaylandSocket {
+        &self.socket
+    }
+
     pub fn get_display(&self) -> WlDisplay {
         self.get_obj(1).unwrap().try_get_wl_display().unwrap()
     }
 
     pub fn sync(&self) {
-        let callback_id = self.new_obj::<WlCallback>();
+        // The id is only known once the request is sent, so the listener waits on
+        // the lock until it has been filled in.
+        let callback_id = Arc::new(Mutex::new(0));
+        let c_callback_id = callback_id.clone();
 
         let done_pair = Arc::new((Mutex::new(false), Condvar::new()));
         let c_done_pair = done_pair.clone();
-        self.add_event_listener(Box::new(move |ev| match ev {
-            wayland::Event::WlCallbackEvent(callback_ev) => match callback_ev {
-                wayland::WlCallbackEvent::WlCallbackdoneEvent(done) => {
-                    if done.sender_id == callback_id {
-                        info!("Callback id {} Done", callback_id);
-                        let &(ref done, ref cond_var) = &*c_done_pair;
-                        *(done.lock().unwrap()) = true;
-                        cond_var.notify_all();
-                    }
+        self.add_event_listener(Box::new(move |ev| {
+            if let wayland::Event::WlCallbackEvent(wayland::WlCallbackEvent::WlCallbackdoneEvent(
+                done,
+            )) = ev
+            {
+                let callback_id = *c_callback_id.lock().unwrap();
+                if done.sender_id == callback_id {
+                    info!("Callback id {} Done", callback_id);
+                    let (done, cond_var) = &*c_done_pair;
+                    *(done.lock().unwrap()) = true;
+                    cond_var.notify_all();
                 }
-                _ => {}
-            },
-            _ => {}
+            }
         }));
-        self.get_display().sync(callback_id);
+        {
+            let mut callback_id = callback_id.lock().unwrap();
+            *callback_id = self.get_display().sync().object_id;
+        }
 
-        let &(ref done, ref cond_var) = &*done_pair;
+        let (done, cond_var) = &*done_pair;
         let mut done = done.lock().unwrap();
         while !*done {
             done = cond_var.wait(done).unwrap();
@@ -85,31 +166,75 @@ impl Client {
         Some(self.obj_map.lock().unwrap().get(&obj_id)?.clone())
     }
 
-    pub fn new_obj<T: WlRawObject>(&self) -> u32 {
+    pub fn new_obj<T: WlRawObject + Clone>(&self, version: u32) -> T {
         let mut hash_map = self.obj_map.lock().unwrap();
         let mut now_max = self.max_id.lock().unwrap();
 
-        let new_id = now_max.clone() + 1;
-        let wl_obj = Arc::new(T::new(new_id, self.socket.clone()).to_enum());
-        hash_map.insert(new_id, wl_obj.clone());
-        *(now_max) += 1;
+        let new_id = match self.free_ids.lock().unwrap().pop() {
+            Some(free_id) => free_id,
+            None => {
+                *(now_max) += 1;
+                *now_max
+            }
+        };
+        let obj = T::new(new_id, version, self.clone());
+        hash_map.insert(new_id, Arc::new(obj.clone().to_enum()));
 
-        return new_id;
+        obj
     }
 
     pub fn delete_obj(&self, obj_id: u32) {
         self.obj_map.lock().unwrap().remove(&obj_id);
+        self.zombies.lock().unwrap().remove(&obj_id);
+        if obj_id < SERVER_ID_START {
+            self.free_ids.lock().unwrap().push(obj_id);
+        }
     }
 
-    pub fn bind_obj<T: WlRawObject>(&self, obj_id: u32) {
-        let wl_obj = Arc::new(T::new(obj_id, self.socket.clone()).to_enum());
-        self.obj_map.lock().unwrap().insert(obj_id, wl_obj.clone());
+    // The server only sends delete_id for ids allocated by the client, its own
+    // ids are free as soon as the destructor is sent.
+    pub fn destroy_obj(&self, obj_id: u32) {
+        if obj_id >= SERVER_ID_START {
+            self.obj_map.lock().unwrap().remove(&obj_id);
+        } else {
+            self.mark_zombie(obj_id);
+        }
+    }
+
+    pub fn mark_zombie(&self, obj_id: u32) {
+        self.zombies.lock().unwrap().insert(obj_id);
+    }
 
-        let now_max = self.max_id.lock().unwrap().clone();
+    pub fn is_zombie(&self, obj_id: u32) -> bool {
+        self.zombies.lock().unwrap().contains(&obj_id)
+    }
+
+    pub fn bind_obj<T: WlRawObject + Clone>(&self, obj_id: u32, version: u32) -> T {
+        let obj = T::new(obj_id, version, self.clone());
+        self.obj_map
+            .lock()
+            .unwrap()
+            .insert(obj_id, Arc::new(obj.clone().to_enum()));
+
+        let now_max = *self.max_id.lock().unwrap();
         *(self.max_id.lock().unwrap()) = std::cmp::max(now_max, obj_id);
+
+        obj
+    }
+
+    // Objects the server creates through events have ids from its own range,
+    // which must not move `max_id` into it.
+    pub fn insert_server_obj<T: WlRawObject + Clone>(&self, obj_id: u32, version: u32) -> T {
+        let obj = T::new(obj_id, version, self.clone());
+        self.obj_map
+            .lock()
+            .unwrap()
+            .insert(obj_id, Arc::new(obj.clone().to_enum()));
+
+        obj
     }
 
-    pub fn add_event_listener(&self, event_handler: Box<Fn(&wayland::Event) + Send + Sync>) {
+    pub fn add_event_listener(&self, event_handler: EventListener) {
         self.event_listeners.write().unwrap().push(event_handler);
     }
 
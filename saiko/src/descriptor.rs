@@ -0,0 +1,52 @@
+use std::fmt;
+
+/// Describes an interface at runtime, like libwayland's `wl_interface`.
+///
+/// Every generated interface has one as `Interface::DESCRIPTOR`, and every
+/// generated module lists its own in `INTERFACES`.
+pub struct InterfaceDescriptor {
+    pub name: &'static str,
+    pub version: u32,
+    /// Indexed by opcode.
+    pub requests: &'static [MessageDescriptor],
+    /// Indexed by opcode.
+    pub events: &'static [MessageDescriptor],
+}
+
+impl InterfaceDescriptor {
+    pub fn request(&self, opcode: u16) -> Option<&'static MessageDescriptor> {
+        self.requests.get(opcode as usize)
+    }
+
+    pub fn event(&self, opcode: u16) -> Option<&'static MessageDescriptor> {
+        self.events.get(opcode as usize)
+    }
+}
+
+// Interfaces refer to each other through their messages, so only the name and
+// version are printed.
+impl fmt::Debug for InterfaceDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InterfaceDescriptor")
+            .field("name", &self.name)
+            .field("version", &self.version)
+            .finish()
+    }
+}
+
+/// Describes a request or an event, like libwayland's `wl_message`.
+#[derive(Debug)]
+pub struct MessageDescriptor {
+    pub name: &'static str,
+    /// The interface version the message was added in.
+    pub since: u32,
+    /// One character per value on the wire: `i` int, `u` uint, `f` fixed, `s`
+    /// string, `o` object, `n` new_id, `a` array and `h` fd. A `?` marks the
+    /// following string or object as nullable. A new_id without an interface
+    /// is sent as `sun`, the interface name, its version and the id.
+    pub signature: &'static str,
+    /// The interface of every object and new_id, one entry per type character
+    /// of the signature. `None` for other values and for objects of any
+    /// interface.
+    pub types: &'static [Option<&'static InterfaceDescriptor>],
+}
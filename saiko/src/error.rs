@@ -0,0 +1,100 @@
+use std::error::Error;
+use std::fmt;
+
+// Sending a request the object's version does not know about is a protocol
+// error the compositor answers by closing the connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionError {
+    pub interface: &'static str,
+    pub request: &'static str,
+    pub since: u32,
+    pub version: u32,
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}.{} requires version {}, but the object has version {}",
+            self.interface, self.request, self.since, self.version
+        )
+    }
+}
+
+impl Error for VersionError {}
+
+// Destructors take the proxy, which is handed back when the request cannot be
+// sent, e.g. to destroy it with a request of an older version instead.
+pub struct DestroyError<T> {
+    pub proxy: T,
+    pub error: VersionError,
+}
+
+impl<T> fmt::Debug for DestroyError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DestroyError")
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for DestroyError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl<T> Error for DestroyError<T> {}
+
+// Messages from the compositor are checked against the signature they are
+// decoded with instead of being trusted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WireError {
+    UnexpectedEnd { needed: usize, remaining: usize },
+    InvalidSize(u16),
+    InvalidString,
+    UnexpectedNull,
+    MissingFd,
+    UnknownOpcode(u16),
+    UnknownObject(u32),
+    Disconnected,
+    Io(i32),
+    InvalidSignature(char),
+    ArgumentMismatch(usize),
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WireError::UnexpectedEnd { needed, remaining } => write!(
+                f,
+                "message ends early: {} more bytes needed, {} left",
+                needed, remaining
+            ),
+            WireError::InvalidSize(size) => write!(f, "invalid message size {}", size),
+            WireError::InvalidString => write!(f, "string is not NUL terminated UTF-8"),
+            WireError::UnexpectedNull => write!(f, "null value for a non-nullable argument"),
+            WireError::MissingFd => write!(f, "message is missing a file descriptor"),
+            WireError::UnknownOpcode(opcode) => write!(f, "unknown opcode {}", opcode),
+            WireError::UnknownObject(object_id) => write!(f, "unknown object {}", object_id),
+            WireError::Disconnected => write!(f, "the peer closed the connection"),
+            WireError::Io(errno) => write!(
+                f,
+                "failed to read from the socket: {}",
+                std::io::Error::from_raw_os_error(*errno)
+            ),
+            WireError::InvalidSignature(typ) => write!(f, "invalid signature type '{}'", typ),
+            WireError::ArgumentMismatch(index) => write!(
+                f,
+                "argument {} is missing or does not match the signature",
+                index
+            ),
+            WireError::TrailingBytes(len) => {
+                write!(f, "{} bytes left after the last argument", len)
+            }
+        }
+    }
+}
+
+impl Error for WireError {}
@@ -1,13 +1,22 @@
-#![feature(str_as_mut_ptr)]
-#![feature(stmt_expr_attributes)]
-
 extern crate failure;
+// Re-exported for the code generated by `protocol!`.
+#[doc(hidden)]
 #[macro_use]
-extern crate log;
+pub extern crate log;
 extern crate byteorder;
 extern crate nix;
+extern crate wayland_protocol_macro;
+
+pub use wayland_protocol_macro::protocol;
 
 pub mod client;
+pub mod descriptor;
+pub mod error;
+pub mod message;
+pub mod server;
 pub mod socket;
+pub mod types;
 mod unix_socket;
 pub mod wayland;
+pub mod wayland_server;
+pub mod wire;
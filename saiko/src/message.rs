@@ -0,0 +1,215 @@
+use crate::error::WireError;
+use crate::types::Fixed;
+use crate::wire::{MessageHeader, MessageReader, MessageWriter};
+use std::collections::VecDeque;
+use std::os::unix::io::{AsRawFd, OwnedFd, RawFd};
+
+/// A value of a message, for code that does not know the protocol at compile
+/// time.
+#[derive(Debug)]
+pub enum Argument {
+    Int(i32),
+    Uint(u32),
+    Fixed(Fixed),
+    /// `None` for a null string.
+    Str(Option<String>),
+    /// `None` for a null object.
+    Object(Option<u32>),
+    NewId(u32),
+    Array(Vec<u8>),
+    Fd(OwnedFd),
+}
+
+/// A request or an event with its arguments in signature order.
+#[derive(Debug)]
+pub struct Message {
+    pub sender: u32,
+    pub opcode: u16,
+    pub args: Vec<Argument>,
+}
+
+// Returns the type character of every value in a signature and whether it may
+// be null.
+fn parse_signature(signature: &str) -> Result<Vec<(char, bool)>, WireError> {
+    let mut types = Vec::new();
+    let mut nullable = false;
+    for typ in signature.chars() {
+        match typ {
+            '?' => nullable = true,
+            'i' | 'u' | 'f' | 's' | 'o' | 'n' | 'a' | 'h' => {
+                types.push((typ, nullable));
+                nullable = false;
+            }
+            // libwayland prefixes signatures with the version the message was
+            // added in.
+            '0'..='9' => {}
+            _ => return Err(WireError::InvalidSignature(typ)),
+        }
+    }
+    Ok(types)
+}
+
+impl Message {
+    /// Decodes the body of a message against a signature like
+    /// `MessageDescriptor::signature`, taking its fds from the front of `fds`.
+    pub fn decode(
+        header: &MessageHeader,
+        body: &[u8],
+        signature: &str,
+        fds: &mut VecDeque<OwnedFd>,
+    ) -> Result<Message, WireError> {
+        let mut reader = MessageReader::with_fds(body, fds);
+        let args = parse_signature(signature)?
+            .into_iter()
+            .map(|(typ, nullable)| {
+                let arg = match typ {
+                    'i' => Argument::Int(reader.int()?),
+                    'u' => Argument::Uint(reader.uint()?),
+                    'f' => Argument::Fixed(reader.fixed()?),
+                    's' => Argument::Str(reader.string()?),
+                    'o' => Argument::Object(reader.object()?),
+                    'n' => Argument::NewId(reader.new_id()?),
+                    'a' => Argument::Array(reader.array()?),
+                    _ => Argument::Fd(reader.fd()?),
+                };
+                match arg {
+                    Argument::Str(None) | Argument::Object(None) if !nullable => {
+                        Err(WireError::UnexpectedNull)
+                    }
+                    arg => Ok(arg),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if !reader.is_empty() {
+            return Err(WireError::TrailingBytes(reader.remaining()));
+        }
+        Ok(Message {
+            sender: header.sender_id,
+            opcode: header.opcode,
+            args,
+        })
+    }
+
+    /// Encodes the message like `MessageWriter::finish`, after checking the
+    /// arguments against the signature. The fds stay owned by the message and
+    /// have to stay open until it is sent.
+    pub fn encode(&self, signature: &str) -> Result<(Vec<u8>, Vec<RawFd>), WireError> {
+        let types = parse_signature(signature)?;
+        if types.len() != self.args.len() {
+            return Err(WireError::ArgumentMismatch(
+                types.len().min(self.args.len()),
+            ));
+        }
+        let mut writer = MessageWriter::new(self.sender, self.opcode);
+        for (index, (&(typ, nullable), arg)) in types.iter().zip(&self.args).enumerate() {
+            match (typ, arg) {
+                ('i', Argument::Int(value)) => writer.put_int(*value),
+                ('u', Argument::Uint(value)) => writer.put_uint(*value),
+                ('f', Argument::Fixed(value)) => writer.put_fixed(*value),
+                ('s', Argument::Str(value)) if nullable || value.is_some() => {
+                    writer.put_string(value.as_deref())
+                }
+                ('o', Argument::Object(object_id)) if nullable || object_id.is_some() => {
+                    writer.put_object(object_id.unwrap_or(0))
+                }
+                ('n', Argument::NewId(object_id)) => writer.put_new_id(*object_id),
+                ('a', Argument::Array(value)) => writer.put_array(value),
+                ('h', Argument::Fd(fd)) => writer.put_fd(fd.as_raw_fd()),
+                _ => return Err(WireError::ArgumentMismatch(index)),
+            }
+        }
+        Ok(writer.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wire::HEADER_SIZE;
+
+    fn decode(buffer: &[u8], signature: &str) -> Result<Message, WireError> {
+        let header = MessageHeader::parse(buffer)?;
+        Message::decode(
+            &header,
+            &buffer[HEADER_SIZE..],
+            signature,
+            &mut VecDeque::new(),
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let message = Message {
+            sender: 2,
+            opcode: 0,
+            args: vec![
+                Argument::Uint(1),
+                Argument::Str(Some("wl_shm".to_string())),
+                Argument::Uint(1),
+                Argument::NewId(5),
+                Argument::Object(None),
+            ],
+        };
+        let (buffer, fds) = message.encode("usun?o").unwrap();
+        assert!(fds.is_empty());
+        let decoded = decode(&buffer, "usun?o").unwrap();
+        assert_eq!((decoded.sender, decoded.opcode), (2, 0));
+        match &decoded.args[..] {
+            [Argument::Uint(1), Argument::Str(Some(name)), Argument::Uint(1), Argument::NewId(5), Argument::Object(None)] =>
+            {
+                assert_eq!(name, "wl_shm")
+            }
+            args => panic!("unexpected arguments {:?}", args),
+        }
+    }
+
+    #[test]
+    fn trailing_bytes_are_rejected() {
+        let message = Message {
+            sender: 3,
+            opcode: 1,
+            args: vec![Argument::Int(-1), Argument::Int(2)],
+        };
+        let (buffer, _) = message.encode("ii").unwrap();
+        assert_eq!(
+            decode(&buffer, "i").unwrap_err(),
+            WireError::TrailingBytes(4)
+        );
+    }
+
+    #[test]
+    fn nulls_need_a_nullable_signature() {
+        let message = Message {
+            sender: 3,
+            opcode: 1,
+            args: vec![Argument::Object(None)],
+        };
+        assert_eq!(
+            message.encode("o").unwrap_err(),
+            WireError::ArgumentMismatch(0)
+        );
+        let (buffer, _) = message.encode("?o").unwrap();
+        assert_eq!(decode(&buffer, "o").unwrap_err(), WireError::UnexpectedNull);
+    }
+
+    #[test]
+    fn invalid_signatures() {
+        let message = Message {
+            sender: 3,
+            opcode: 1,
+            args: vec![Argument::Int(0)],
+        };
+        assert_eq!(
+            message.encode("x").unwrap_err(),
+            WireError::InvalidSignature('x')
+        );
+        assert_eq!(
+            message.encode("ii").unwrap_err(),
+            WireError::ArgumentMismatch(1)
+        );
+        assert_eq!(
+            message.encode("u").unwrap_err(),
+            WireError::ArgumentMismatch(0)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::error::WireError;
+use crate::socket::WaylandSocket;
+use crate::wayland_server::{Request, WlDisplay, WlRawResource, WlResource};
+use crate::wire::SERVER_ID_START;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// The untyped new_id of a request such as wl_registry.bind, to be turned into
+/// a resource with `Connection::create_resource` once the server has checked
+/// the interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewResource {
+    pub interface: String,
+    pub version: u32,
+    pub id: u32,
+}
+
+/// The server's end of the connection to one client.
+#[derive(Clone)]
+pub struct Connection {
+    socket: Arc<WaylandSocket>,
+    pub resources: Arc<Mutex<HashMap<u32, Arc<WlResource>>>>,
+    next_id: Arc<Mutex<u32>>,
+}
+
+impl Connection {
+    pub fn new(socket: WaylandSocket) -> Connection {
+        let connection = Connection {
+            socket: Arc::new(socket),
+            resources: Arc::new(Mutex::new(HashMap::new())),
+            next_id: Arc::new(Mutex::new(SERVER_ID_START)),
+        };
+        connection.create_resource::<WlDisplay>(1, 1);
+
+        connection
+    }
+
+    pub fn socket(&self) -> &WaylandSocket {
+        &self.socket
+    }
+
+    pub fn get_display(&self) -> WlDisplay {
+        self.get_resource(1).unwrap().try_get_wl_display().unwrap()
+    }
+
+    pub fn get_resource(&self, id: u32) -> Option<Arc<WlResource>> {
+        Some(self.resources.lock().unwrap().get(&id)?.clone())
+    }
+
+    /// Creates the resource for an id the client allocated.
+    pub fn create_resource<T: WlRawResource + Clone>(&self, id: u32, version: u32) -> T {
+        let resource = T::new(id, version, self.clone());
+        self.resources
+            .lock()
+            .unwrap()
+            .insert(id, Arc::new(resource.clone().to_enum()));
+
+        resource
+    }
+
+    /// Creates a resource with an id allocated by the server, for events that
+    /// introduce new objects.
+    pub fn new_resource<T: WlRawResource + Clone>(&self, version: u32) -> T {
+        let id = {
+            let mut next_id = self.next_id.lock().unwrap();
+            *next_id += 1;
+            *next_id - 1
+        };
+        self.create_resource(id, version)
+    }
+
+    /// Forgets a resource. Ids of the client are handed back to it with
+    /// wl_display.delete_id, so destructor requests have to end up here once
+    /// they are handled.
+    pub fn destroy_resource(&self, id: u32) {
+        self.resources.lock().unwrap().remove(&id);
+        if id < SERVER_ID_START {
+            self.get_display().delete_id(id);
+        }
+    }
+
+    /// Reads the next batch of requests from the client.
+    ///
+    /// Returns `WireError::Disconnected` once the client has closed the
+    /// connection. Any other error is a protocol error, after which the client
+    /// should be disconnected.
+    pub fn read_requests(&self) -> Result<Vec<Request>, WireError> {
+        let mut requests = Vec::new();
+        for (header, msg_body) in self.socket.read_event()? {
+            let resource = self
+                .get_resource(header.sender_id)
+                .ok_or(WireError::UnknownObject(header.sender_id))?;
+            requests.push(resource.parse_request(header.sender_id, header.opcode, &msg_body)?);
+        }
+        Ok(requests)
+    }
+}
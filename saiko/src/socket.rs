@@ -1,13 +1,18 @@
-use super::wayland::EventHeader;
-use super::wayland::ReadEvent;
+use crate::error::WireError;
+use crate::message::Message;
 use crate::unix_socket::UnixSocket;
-use std::os::unix::io::RawFd;
+use crate::wire::{MessageHeader, HEADER_SIZE};
+use std::collections::VecDeque;
+use std::os::unix::io::{FromRawFd, OwnedFd, RawFd};
 use std::sync::Arc;
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 
 pub struct WaylandSocket {
     write_stream: Arc<Mutex<UnixSocket>>,
     read_stream: Arc<Mutex<UnixSocket>>,
+    received_fds: Mutex<VecDeque<OwnedFd>>,
+    // Bytes of a message that has not been received completely yet.
+    pending: Mutex<Vec<u8>>,
 }
 
 impl WaylandSocket {
@@ -22,13 +27,18 @@ impl WaylandSocket {
             path.to_path_buf()
         };
 
-        let socket = UnixSocket::connect(path);
+        WaylandSocket::new(UnixSocket::connect(path))
+    }
+
+    fn new(socket: UnixSocket) -> WaylandSocket {
         let write_stream = Arc::new(Mutex::new(socket.clone()));
         let read_stream = Arc::new(Mutex::new(socket.clone()));
 
         WaylandSocket {
             write_stream,
             read_stream,
+            received_fds: Mutex::new(VecDeque::new()),
+            pending: Mutex::new(Vec::new()),
         }
     }
 
@@ -41,7 +51,82 @@ impl WaylandSocket {
         self.write_stream.lock().unwrap().write(buffer, fd);
     }
 
-    pub fn read_event(&self) -> Vec<(EventHeader, std::vec::Vec<u8>)> {
-        self.read_stream.lock().unwrap().read_event()
+    pub fn read_event(&self) -> Result<Vec<(MessageHeader, Vec<u8>)>, WireError> {
+        let mut buffer = [0; 4096];
+        let mut fds = Vec::new();
+        let size = self
+            .read_stream
+            .lock()
+            .unwrap()
+            .read(&mut buffer, &mut fds)?;
+        // recvmsg only returns 0 bytes at the end of the stream, anything still
+        // pending is a message that will never be completed.
+        if size == 0 {
+            return Err(WireError::Disconnected);
+        }
+        // The fds are handed out in the order the events that carry them are parsed.
+        self.received_fds.lock().unwrap().extend(
+            fds.into_iter()
+                .map(|fd| unsafe { OwnedFd::from_raw_fd(fd) }),
+        );
+
+        let mut pending = self.pending.lock().unwrap();
+        pending.extend_from_slice(&buffer[..size]);
+        let mut events = Vec::new();
+        let mut read_size = 0;
+        while pending.len() - read_size >= HEADER_SIZE {
+            let header = MessageHeader::parse(&pending[read_size..])?;
+            let msg_size = header.size as usize;
+            if pending.len() - read_size < msg_size {
+                break;
+            }
+            let msg_body = pending[read_size + HEADER_SIZE..read_size + msg_size].to_vec();
+            events.push((header, msg_body));
+            read_size += msg_size;
+        }
+        pending.drain(..read_size);
+        Ok(events)
+    }
+
+    /// Sends a message after checking its arguments against `signature`.
+    pub fn send_message(&self, message: &Message, signature: &str) -> Result<(), WireError> {
+        let (buffer, fds) = message.encode(signature)?;
+        self.send(&buffer, &fds);
+        Ok(())
+    }
+
+    /// Reads the next batch of messages, decoding each with the signature
+    /// `signature` looks up for its header, e.g. from the descriptor of the
+    /// sender's interface.
+    ///
+    /// A message that cannot be decoded does not affect the others of the
+    /// batch, only an error reading the batch itself is returned as a whole.
+    pub fn read_messages<'a, F>(
+        &self,
+        mut signature: F,
+    ) -> Result<Vec<Result<Message, WireError>>, WireError>
+    where
+        F: FnMut(&MessageHeader) -> Result<&'a str, WireError>,
+    {
+        let events = self.read_event()?;
+        let mut fds = self.received_fds();
+        Ok(events
+            .iter()
+            .map(|(header, msg_body)| {
+                Message::decode(header, msg_body, signature(header)?, &mut fds)
+            })
+            .collect())
+    }
+
+    /// Fds received along with events, to be read with `MessageReader::with_fds`.
+    pub fn received_fds(&self) -> MutexGuard<'_, VecDeque<OwnedFd>> {
+        self.received_fds.lock().unwrap()
+    }
+}
+
+// Servers get the sockets of their clients from accept.
+impl FromRawFd for WaylandSocket {
+    unsafe fn from_raw_fd(fd: RawFd) -> WaylandSocket {
+        WaylandSocket::new(UnixSocket::from_fd(fd))
     }
 }
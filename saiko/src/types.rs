@@ -0,0 +1,86 @@
+use byteorder::{ByteOrder, NativeEndian};
+
+// Arrays are sent as raw bytes in the host byte order, most of them hold u32s
+// (e.g. the pressed keys of wl_keyboard.enter).
+pub fn array_to_u32s(array: &[u8]) -> Vec<u32> {
+    array.chunks_exact(4).map(NativeEndian::read_u32).collect()
+}
+
+pub fn u32s_to_array(values: &[u32]) -> Vec<u8> {
+    let mut array = vec![0; values.len() * 4];
+    NativeEndian::write_u32_into(values, &mut array);
+    array
+}
+
+// wl_fixed_t: a signed 24.8 fixed point number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    pub fn from_raw(raw: i32) -> Fixed {
+        Fixed(raw)
+    }
+
+    pub fn to_raw(self) -> i32 {
+        self.0
+    }
+
+    pub fn from_f64(value: f64) -> Fixed {
+        Fixed((value * 256.0).round() as i32)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.0) / 256.0
+    }
+
+    /// Values outside of the 24 bit integer part saturate at the smallest or
+    /// largest fixed, like in `from_f64`.
+    pub fn from_int(value: i32) -> Fixed {
+        Fixed(value.saturating_mul(256))
+    }
+
+    /// Returns `None` for values outside of the 24 bit integer part.
+    pub fn checked_from_int(value: i32) -> Option<Fixed> {
+        value.checked_mul(256).map(Fixed)
+    }
+
+    pub fn to_int(self) -> i32 {
+        self.0 / 256
+    }
+}
+
+// Saturates like `Fixed::from_int`.
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Fixed {
+        Fixed::from_int(value)
+    }
+}
+
+impl From<f64> for Fixed {
+    fn from(value: f64) -> Fixed {
+        Fixed::from_f64(value)
+    }
+}
+
+impl From<Fixed> for f64 {
+    fn from(value: Fixed) -> f64 {
+        value.to_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_from_int_out_of_range() {
+        assert_eq!(Fixed::from_int(-3).to_int(), -3);
+        assert_eq!(
+            Fixed::checked_from_int((1 << 23) - 1),
+            Some(Fixed(0x7fff_ff00))
+        );
+        assert_eq!(Fixed::checked_from_int(1 << 24), None);
+        assert_eq!(Fixed::from_int(1 << 24), Fixed(i32::MAX));
+        assert_eq!(Fixed::from(-(1 << 24)), Fixed(i32::MIN));
+    }
+}
@@ -1,12 +1,15 @@
-use byteorder::WriteBytesExt;
+use crate::error::WireError;
+use nix::errno::Errno;
 use nix::sys::socket;
 use nix::sys::socket::ControlMessage;
 use nix::sys::socket::MsgFlags;
 use nix::sys::socket::{recvmsg, sendmsg, CmsgSpace};
 use nix::sys::uio::IoVec;
-use std::io::Cursor;
 use std::os::unix::io::RawFd;
 
+// libwayland never sends more than this many fds along with a single buffer.
+const MAX_FDS: usize = 28;
+
 #[derive(Clone)]
 pub struct UnixSocket {
     fd: RawFd,
@@ -28,7 +31,11 @@ impl UnixSocket {
         )
         .unwrap();
 
-        return UnixSocket { fd };
+        UnixSocket { fd }
+    }
+
+    pub fn from_fd(fd: RawFd) -> UnixSocket {
+        UnixSocket { fd }
     }
 
     pub fn write(&mut self, buffer: &[u8], fds: &[RawFd]) {
@@ -37,26 +44,34 @@ impl UnixSocket {
         sendmsg(self.fd, &iov, &cmsg, MsgFlags::empty(), None).unwrap();
     }
 
-    pub fn read(&mut self, buffer: &mut [u8], fds: &mut [u8]) -> (usize, i32) {
+    pub fn read(&mut self, buffer: &mut [u8], fds: &mut Vec<RawFd>) -> Result<usize, WireError> {
         let iov: [IoVec<&mut [u8]>; 1] = [IoVec::from_mut_slice(buffer); 1];
-        let mut cmsg: CmsgSpace<[RawFd; 1]> = CmsgSpace::new();
+        let mut cmsg: CmsgSpace<[RawFd; MAX_FDS]> = CmsgSpace::new();
 
-        let msg = recvmsg(self.fd, &iov, Some(&mut cmsg), MsgFlags::empty()).unwrap();
+        let msg = loop {
+            match recvmsg(self.fd, &iov, Some(&mut cmsg), MsgFlags::MSG_CMSG_CLOEXEC) {
+                Ok(msg) => break msg,
+                Err(err) => match err.as_errno() {
+                    Some(Errno::EINTR) => continue,
+                    // A peer that exits with unread data resets the connection.
+                    Some(Errno::ECONNRESET) | Some(Errno::EPIPE) => {
+                        return Err(WireError::Disconnected)
+                    }
+                    errno => {
+                        return Err(WireError::Io(errno.unwrap_or(Errno::UnknownErrno) as i32))
+                    }
+                },
+            }
+        };
 
-        let mut num_fds = 0;
-        let mut buf = Cursor::new(fds);
         for cmsg in msg.cmsgs() {
-            match cmsg {
-                ControlMessage::ScmRights(newfds) => {
-                    buf.write_i32::<byteorder::NativeEndian>(newfds[0]).unwrap();
-                    num_fds += 1;
-                }
-                _ => {}
+            if let ControlMessage::ScmRights(newfds) = cmsg {
+                fds.extend_from_slice(newfds);
             }
         }
 
-        info!("Read {} bytes", msg.bytes);
-        return (msg.bytes, num_fds);
+        info!("Read {} bytes and {} fds", msg.bytes, fds.len());
+        Ok(msg.bytes)
     }
 
     pub fn shutdown(&mut self) {
@@ -0,0 +1 @@
+include!(concat!(env!("OUT_DIR"), "/wayland_server.rs"));
@@ -0,0 +1,369 @@
+use crate::error::WireError;
+use crate::types::Fixed;
+use byteorder::{ByteOrder, NativeEndian};
+use std::collections::VecDeque;
+use std::os::unix::io::{OwnedFd, RawFd};
+
+pub const HEADER_SIZE: usize = 8;
+
+// Ids below this are allocated by the client, the rest by the server.
+pub const SERVER_ID_START: u32 = 0xff00_0000;
+
+// Every argument is padded to 32 bits.
+fn padded(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageHeader {
+    pub sender_id: u32,
+    pub opcode: u16,
+    /// Size of the whole message, header included.
+    pub size: u16,
+}
+
+impl MessageHeader {
+    pub fn parse(buffer: &[u8]) -> Result<MessageHeader, WireError> {
+        let mut reader = MessageReader::new(buffer);
+        let sender_id = reader.uint()?;
+        let size_and_opcode = reader.uint()?;
+        let size = (size_and_opcode >> 16) as u16;
+        if (size as usize) < HEADER_SIZE || padded(size as usize) != size as usize {
+            return Err(WireError::InvalidSize(size));
+        }
+        Ok(MessageHeader {
+            sender_id,
+            opcode: size_and_opcode as u16,
+            size,
+        })
+    }
+}
+
+pub struct MessageWriter {
+    buffer: Vec<u8>,
+    fds: Vec<RawFd>,
+}
+
+impl MessageWriter {
+    pub fn new(sender_id: u32, opcode: u16) -> MessageWriter {
+        let mut writer = MessageWriter {
+            buffer: Vec::with_capacity(HEADER_SIZE),
+            fds: Vec::new(),
+        };
+        writer.put_uint(sender_id);
+        // The size is filled in by finish.
+        writer.put_uint(u32::from(opcode));
+        writer
+    }
+
+    pub fn put_int(&mut self, value: i32) {
+        self.put_uint(value as u32);
+    }
+
+    pub fn put_uint(&mut self, value: u32) {
+        let mut bytes = [0; 4];
+        NativeEndian::write_u32(&mut bytes, value);
+        self.buffer.extend_from_slice(&bytes);
+    }
+
+    pub fn put_fixed(&mut self, value: Fixed) {
+        self.put_int(value.to_raw());
+    }
+
+    /// Null objects are sent as id 0.
+    pub fn put_object(&mut self, object_id: u32) {
+        self.put_uint(object_id);
+    }
+
+    pub fn put_new_id(&mut self, object_id: u32) {
+        self.put_uint(object_id);
+    }
+
+    /// Strings are sent with their length including the terminating NUL, and a
+    /// null string as a length of 0.
+    pub fn put_string(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.put_uint(value.len() as u32 + 1);
+                self.buffer.extend_from_slice(value.as_bytes());
+                self.buffer.push(0);
+                self.pad();
+            }
+            None => self.put_uint(0),
+        }
+    }
+
+    pub fn put_array(&mut self, value: &[u8]) {
+        self.put_uint(value.len() as u32);
+        self.buffer.extend_from_slice(value);
+        self.pad();
+    }
+
+    /// The fd is sent out of band, it has to stay open until the message is
+    /// sent.
+    pub fn put_fd(&mut self, fd: RawFd) {
+        self.fds.push(fd);
+    }
+
+    fn pad(&mut self) {
+        let len = padded(self.buffer.len());
+        self.buffer.resize(len, 0);
+    }
+
+    /// Returns the encoded message and the fds to send along with it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message does not fit into the 16 bit size of the header.
+    pub fn finish(mut self) -> (Vec<u8>, Vec<RawFd>) {
+        let size = self.buffer.len();
+        assert!(
+            size <= u16::MAX as usize,
+            "message of {} bytes is too large",
+            size
+        );
+        let opcode = NativeEndian::read_u32(&self.buffer[4..HEADER_SIZE]);
+        NativeEndian::write_u32(
+            &mut self.buffer[4..HEADER_SIZE],
+            (size as u32) << 16 | opcode,
+        );
+        (self.buffer, self.fds)
+    }
+}
+
+pub struct MessageReader<'a> {
+    body: &'a [u8],
+    fds: Option<&'a mut VecDeque<OwnedFd>>,
+}
+
+impl<'a> MessageReader<'a> {
+    pub fn new(body: &'a [u8]) -> MessageReader<'a> {
+        MessageReader { body, fds: None }
+    }
+
+    /// Fds are taken from the front of `fds` in the order they are read.
+    pub fn with_fds(body: &'a [u8], fds: &'a mut VecDeque<OwnedFd>) -> MessageReader<'a> {
+        MessageReader {
+            body,
+            fds: Some(fds),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.body.is_empty()
+    }
+
+    /// The number of bytes that have not been read yet.
+    pub fn remaining(&self) -> usize {
+        self.body.len()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], WireError> {
+        if self.body.len() < len {
+            return Err(WireError::UnexpectedEnd {
+                needed: len,
+                remaining: self.body.len(),
+            });
+        }
+        let (value, rest) = self.body.split_at(len);
+        self.body = rest;
+        Ok(value)
+    }
+
+    pub fn int(&mut self) -> Result<i32, WireError> {
+        Ok(self.uint()? as i32)
+    }
+
+    pub fn uint(&mut self) -> Result<u32, WireError> {
+        Ok(NativeEndian::read_u32(self.take(4)?))
+    }
+
+    pub fn fixed(&mut self) -> Result<Fixed, WireError> {
+        Ok(Fixed::from_raw(self.int()?))
+    }
+
+    /// Returns `None` for a null object.
+    pub fn object(&mut self) -> Result<Option<u32>, WireError> {
+        match self.uint()? {
+            0 => Ok(None),
+            object_id => Ok(Some(object_id)),
+        }
+    }
+
+    pub fn new_id(&mut self) -> Result<u32, WireError> {
+        self.object()?.ok_or(WireError::UnexpectedNull)
+    }
+
+    /// Returns `None` for a null string.
+    pub fn string(&mut self) -> Result<Option<String>, WireError> {
+        let len = self.uint()? as usize;
+        if len == 0 {
+            return Ok(None);
+        }
+        let value = &self.take(padded(len))?[..len];
+        match value.split_last() {
+            Some((0, value)) => match std::str::from_utf8(value) {
+                Ok(value) => Ok(Some(value.to_string())),
+                Err(_) => Err(WireError::InvalidString),
+            },
+            _ => Err(WireError::InvalidString),
+        }
+    }
+
+    pub fn array(&mut self) -> Result<Vec<u8>, WireError> {
+        let len = self.uint()? as usize;
+        Ok(self.take(padded(len))?[..len].to_vec())
+    }
+
+    pub fn fd(&mut self) -> Result<OwnedFd, WireError> {
+        match &mut self.fds {
+            Some(fds) => fds.pop_front().ok_or(WireError::MissingFd),
+            None => Err(WireError::MissingFd),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(words: &[u32]) -> Vec<u8> {
+        let mut body = vec![0; words.len() * 4];
+        NativeEndian::write_u32_into(words, &mut body);
+        body
+    }
+
+    #[test]
+    fn header_size_is_checked() {
+        let header = body(&[3, 12 << 16 | 1]);
+        assert_eq!(
+            MessageHeader::parse(&header),
+            Ok(MessageHeader {
+                sender_id: 3,
+                opcode: 1,
+                size: 12
+            })
+        );
+        assert_eq!(
+            MessageHeader::parse(&body(&[3, 4 << 16])),
+            Err(WireError::InvalidSize(4))
+        );
+        assert_eq!(
+            MessageHeader::parse(&body(&[3, 10 << 16])),
+            Err(WireError::InvalidSize(10))
+        );
+        assert_eq!(
+            MessageHeader::parse(&header[..6]),
+            Err(WireError::UnexpectedEnd {
+                needed: 4,
+                remaining: 2
+            })
+        );
+    }
+
+    #[test]
+    fn truncated_body() {
+        let body = body(&[1]);
+        let mut reader = MessageReader::new(&body[..3]);
+        assert_eq!(
+            reader.uint(),
+            Err(WireError::UnexpectedEnd {
+                needed: 4,
+                remaining: 3
+            })
+        );
+
+        // The length of the string claims more bytes than there are.
+        let mut string = self::body(&[8]);
+        string.extend_from_slice(b"abc\0");
+        assert_eq!(
+            MessageReader::new(&string).string(),
+            Err(WireError::UnexpectedEnd {
+                needed: 8,
+                remaining: 4
+            })
+        );
+    }
+
+    #[test]
+    fn strings_need_a_nul() {
+        let mut string = body(&[4]);
+        string.extend_from_slice(b"abcd");
+        assert_eq!(
+            MessageReader::new(&string).string(),
+            Err(WireError::InvalidString)
+        );
+
+        let mut string = body(&[3]);
+        string.extend_from_slice(b"\xff\xfe\0\0");
+        assert_eq!(
+            MessageReader::new(&string).string(),
+            Err(WireError::InvalidString)
+        );
+    }
+
+    #[test]
+    fn nulls() {
+        let body = body(&[0, 0, 0]);
+        let mut reader = MessageReader::new(&body);
+        assert_eq!(reader.string(), Ok(None));
+        assert_eq!(reader.object(), Ok(None));
+        assert_eq!(reader.new_id(), Err(WireError::UnexpectedNull));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn arrays_are_padded() {
+        let mut writer = MessageWriter::new(2, 0);
+        writer.put_array(&[1, 2, 3, 4, 5]);
+        writer.put_uint(7);
+        let (buffer, fds) = writer.finish();
+        assert!(fds.is_empty());
+        // Header, length, 5 bytes padded to 8 and the uint.
+        assert_eq!(buffer.len(), 24);
+        let header = MessageHeader::parse(&buffer).unwrap();
+        assert_eq!(header.size, 24);
+
+        let mut reader = MessageReader::new(&buffer[HEADER_SIZE..]);
+        assert_eq!(reader.array(), Ok(vec![1, 2, 3, 4, 5]));
+        assert_eq!(reader.uint(), Ok(7));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn strings_round_trip() {
+        let mut writer = MessageWriter::new(2, 0);
+        writer.put_string(Some("wl_shm"));
+        writer.put_string(None);
+        writer.put_fixed(Fixed::from_f64(-1.5));
+        let (buffer, _) = writer.finish();
+        // Length, "wl_shm\0" padded to 8, null string and the fixed.
+        assert_eq!(buffer.len(), HEADER_SIZE + 20);
+
+        let mut reader = MessageReader::new(&buffer[HEADER_SIZE..]);
+        assert_eq!(reader.string(), Ok(Some("wl_shm".to_string())));
+        assert_eq!(reader.string(), Ok(None));
+        assert_eq!(reader.fixed(), Ok(Fixed::from_f64(-1.5)));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn fds_are_taken_in_order() {
+        use std::fs::File;
+        use std::os::unix::io::AsRawFd;
+
+        let files: Vec<OwnedFd> = (0..2)
+            .map(|_| File::open("/dev/null").unwrap().into())
+            .collect();
+        let raw_fds: Vec<RawFd> = files.iter().map(AsRawFd::as_raw_fd).collect();
+        let mut fds: VecDeque<OwnedFd> = files.into_iter().collect();
+        let mut reader = MessageReader::with_fds(&[], &mut fds);
+        assert_eq!(reader.fd().unwrap().as_raw_fd(), raw_fds[0]);
+        assert_eq!(reader.fd().unwrap().as_raw_fd(), raw_fds[1]);
+        assert_eq!(reader.fd().unwrap_err(), WireError::MissingFd);
+        assert_eq!(
+            MessageReader::new(&[]).fd().unwrap_err(),
+            WireError::MissingFd
+        );
+    }
+}
@@ -8,23 +8,136 @@ extern crate quote;
 #[macro_use]
 extern crate lazy_static;
 
-use wayland_protocol_scanner::{Protocol, ProtocolChild, Interface, InterfaceChild, EventOrRequestField};
+use heck::{CamelCase, ShoutySnakeCase, SnakeCase};
 use proc_macro2::{Ident, Span, TokenStream};
-use heck::{CamelCase, SnakeCase};
+use std::fmt;
+use std::path::Path;
+use wayland_protocol_scanner::{
+    Arg, Description, Diagnostic, Entry, Enum, Event, EventOrRequestField, Interface,
+    InterfaceChild, ParseError, Protocol, ProtocolChild, Request,
+};
 
 lazy_static! {
-    static ref PROTOCOL: Protocol = wayland_protocol_scanner::parse_wayland_protocol();
+    static ref PROTOCOL: Protocol =
+        wayland_protocol_scanner::parse_wayland_protocol().unwrap_or_else(|err| panic!("{}", err));
 }
 
-fn escape_name(name: &String) -> String {
+/// A protocol that the generated protocol builds on, other than the core
+/// protocol, e.g. xdg-shell for xdg-decoration.
+pub struct Dependency<'a> {
+    pub protocol: &'a Protocol,
+    /// The path of the module the dependency was generated into, e.g.
+    /// `crate::xdg_shell`. For server code it is the module of its server
+    /// code.
+    pub module: &'a str,
+}
+
+// E.g. `crate::xdg_shell` or `::other_crate::xdg_shell`, possibly with spaces
+// around the separators.
+fn is_module_path(module: &str) -> bool {
+    let module = module.trim();
+    let module = module.strip_prefix("::").unwrap_or(module);
+    module.split("::").all(|segment| {
+        let segment = segment.trim();
+        segment
+            .chars()
+            .next()
+            .is_some_and(|first| first.is_alphabetic() || first == '_')
+            && segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+    })
+}
+
+// Code is either generated for saiko's own `wayland` and `wayland_server`
+// modules, or for a module in another crate which has to refer back to saiko for
+// the core protocol and to the modules of its dependencies.
+struct Context<'a> {
+    protocol: &'a Protocol,
+    dependencies: Vec<(&'a Protocol, TokenStream)>,
+    core: bool,
+    server: bool,
+}
+
+impl<'a> Context<'a> {
+    fn core(server: bool) -> Context<'static> {
+        Context {
+            protocol: &PROTOCOL,
+            dependencies: Vec::new(),
+            core: true,
+            server,
+        }
+    }
+
+    fn extension(
+        protocol: &'a Protocol,
+        dependencies: &[Dependency<'a>],
+        server: bool,
+    ) -> Result<Context<'a>, GenerateError> {
+        let mut protocols = vec![&*PROTOCOL];
+        protocols.extend(dependencies.iter().map(|dependency| dependency.protocol));
+        protocols.push(protocol);
+        let diagnostics = wayland_protocol_scanner::validate_protocols(protocols);
+        if !diagnostics.is_empty() {
+            return Err(GenerateError::Invalid(diagnostics));
+        }
+
+        let dependencies = dependencies
+            .iter()
+            .map(|dependency| match dependency.module.parse() {
+                Ok(module) if is_module_path(dependency.module) => {
+                    Ok((dependency.protocol, module))
+                }
+                _ => Err(GenerateError::InvalidModule(dependency.module.to_string())),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Context {
+            protocol,
+            dependencies,
+            core: false,
+            server,
+        })
+    }
+
+    fn krate(&self) -> TokenStream {
+        if self.core {
+            quote! {crate}
+        } else {
+            quote! {::saiko}
+        }
+    }
+
+    fn type_path(&self, interface: &str, name: Ident) -> TokenStream {
+        let is_local = self
+            .protocol
+            .interfaces()
+            .any(|local| local.name == interface);
+        let dependency = self
+            .dependencies
+            .iter()
+            .find(|(protocol, _)| protocol.interfaces().any(|dep| dep.name == interface));
+        if self.core || is_local {
+            quote! {#name}
+        } else if let Some((_, module)) = dependency {
+            quote! {#module::#name}
+        } else {
+            let krate = self.krate();
+            if self.server {
+                quote! {#krate::wayland_server::#name}
+            } else {
+                quote! {#krate::wayland::#name}
+            }
+        }
+    }
+}
+
+fn escape_name(name: &str) -> String {
     if name == "move" {
         String::from("mv")
     } else {
-        name.clone()
+        name.to_string()
     }
 }
 
-fn construct_indent_from_string(str: &String) -> Ident {
+fn construct_indent_from_string(str: &str) -> Ident {
     Ident::new(str, Span::call_site())
 }
 
@@ -33,13 +146,13 @@ enum Case {
     SnakeCase,
 }
 
-fn construct_ident_from_str_and_case(str: &String, case: Option<Case>) -> Ident {
+fn construct_ident_from_str_and_case(str: &str, case: Option<Case>) -> Ident {
     match case {
         Some(case) => match case {
             Case::CamelCase => construct_indent_from_string(&str.to_camel_case()),
             Case::SnakeCase => construct_indent_from_string(&str.to_snake_case()),
         },
-        None => construct_indent_from_string(&str),
+        None => construct_indent_from_string(str),
     }
 }
 
@@ -47,12 +160,19 @@ macro_rules! ident {
     ($t:expr, $( $s:expr ),*; $c: expr) => (construct_ident_from_str_and_case(&format!($t, $(escape_name(&$s)),*), $c))
 }
 
+mod server;
+
+pub use server::{
+    generate_server_protocol_code, generate_server_protocol_code_from_file,
+    generate_server_protocol_code_with_dependencies, generate_wayland_server_code,
+};
+
 macro_rules! generate_arguments {
-    ($re:expr) => {
+    ($ctx:expr, $interface:expr, $re:expr) => {
         $re.items.iter().filter_map(|child| match child {
             EventOrRequestField::Arg(arg) => {
                 let arg_name = ident!("{}", arg.name; None);
-                let arg_typ = ident!("{}", arg.typ; Some(Case::CamelCase));
+                let arg_typ = arg_type($ctx, $interface, arg);
                 Some(quote! {#arg_name: #arg_typ})
             }
             _ => None,
@@ -60,260 +180,742 @@ macro_rules! generate_arguments {
     }
 }
 
-fn add_arg_size(arg: &wayland_protocol_scanner::Arg) -> Option<TokenStream> {
-    let arg_name = ident!("{}",arg.name; None);
-    let arg_typ = ident!("{}", &arg.typ; Some(Case::CamelCase));
-    match &arg.typ[..] {
-        "String" => Some(quote! {
-            raw_size += ((#arg_name.len() + 1) as f64 / 4.0).ceil() as usize * 4 + 4;
-        }),
-        "Fd" => None,
-        // TODO: Array and other types
-        _ => Some(quote! {raw_size += size_of::<#arg_typ>();}),
+// Enum references are either local (`format`) or qualified with the interface
+// that declares them (`wl_shm.format`).
+fn enum_type_name(ctx: &Context, interface: &Interface, enum_name: &str) -> TokenStream {
+    let (interface_name, enum_name) = match enum_name.find('.') {
+        Some(pos) => (&enum_name[..pos], &enum_name[pos + 1..]),
+        None => (&interface.name[..], enum_name),
+    };
+    let enum_typ = ident!("{}_{}", interface_name, enum_name; Some(Case::CamelCase));
+    ctx.type_path(interface_name, enum_typ)
+}
+
+fn is_enum_arg(arg: &Arg) -> bool {
+    arg.enum_name.is_some() && (arg.typ == "uint" || arg.typ == "int")
+}
+
+fn proxy_interface(arg: &Arg) -> Option<&str> {
+    match &arg.interface {
+        Some(interface) if arg.typ == "object" || arg.typ == "new_id" => Some(interface),
+        _ => None,
     }
 }
 
-fn send_arg(arg: &wayland_protocol_scanner::Arg) -> Option<TokenStream> {
-    let arg_name = ident!("{}",arg.name; None);
-    let arg_typ = ident!("{}", &arg.typ; Some(Case::CamelCase));
-    match &arg.typ.to_camel_case()[..] {
-        "String" => Some(quote! {
-            let str_len = #arg_name.len();
-            let buf_len = ((#arg_name.len() + 1) as f64 / 4.0).ceil() as usize * 4;
-            unsafe {
-                std::ptr::copy(&buf_len as *const usize as *const u8, &mut send_buffer[written_len] as *mut u8, str_len + 1);
-                std::ptr::copy(&#arg_name.into_bytes()[0] as *const u8, &mut send_buffer[written_len + 4] as *mut u8, str_len);
-            }
-            #[allow(unused)]
-            written_len += buf_len + 4;
-        }),
-        "Fd" => Some(quote! {
-            info!("Send FD: {}", #arg_name);
-            send_fd[send_fd_num] = #arg_name;
-            send_fd_num += 1;
-        }),
-        // TODO: Array and other types
-        _ => Some(quote! {
-            unsafe {
-                std::ptr::copy(&#arg_name as *const #arg_typ, &mut send_buffer[written_len] as *mut u8 as *mut #arg_typ, 1);
+// Null objects are sent as id 0 and null strings with a length of 0.
+fn is_nullable(arg: &Arg) -> bool {
+    arg.allow_null && (arg.typ == "object" || arg.typ == "string")
+}
+
+fn is_typed_new_id(arg: &Arg) -> bool {
+    arg.typ == "new_id" && arg.interface.is_some()
+}
+
+// A new_id without an interface is sent as the interface name, the version and
+// the id, and the interface is picked by the caller through a type parameter.
+fn is_untyped_new_id(arg: &Arg) -> bool {
+    arg.typ == "new_id" && arg.interface.is_none()
+}
+
+fn arg_type(ctx: &Context, interface: &Interface, arg: &Arg) -> TokenStream {
+    if let Some(proxy_interface) = proxy_interface(arg) {
+        let proxy_typ = ident!("{}", proxy_interface; Some(Case::CamelCase));
+        let proxy_typ = ctx.type_path(proxy_interface, proxy_typ);
+        if is_nullable(arg) {
+            return quote! {Option<#proxy_typ>};
+        }
+        return quote! {#proxy_typ};
+    }
+    if is_nullable(arg) {
+        return quote! {Option<String>};
+    }
+    if arg.typ == "fd" {
+        return quote! {OwnedFd};
+    }
+    match &arg.enum_name {
+        Some(enum_name) if is_enum_arg(arg) => enum_type_name(ctx, interface, enum_name),
+        _ => {
+            let arg_typ = ident!("{}", arg.typ; Some(Case::CamelCase));
+            quote! {#arg_typ}
+        }
+    }
+}
+
+// Requests only borrow the objects they refer to.
+fn param_type(ctx: &Context, interface: &Interface, arg: &Arg) -> TokenStream {
+    match &arg.interface {
+        Some(object_interface) if arg.typ == "object" => {
+            let proxy_typ = ident!("{}", object_interface; Some(Case::CamelCase));
+            let proxy_typ = ctx.type_path(object_interface, proxy_typ);
+            if is_nullable(arg) {
+                quote! {Option<&#proxy_typ>}
+            } else {
+                quote! {&#proxy_typ}
             }
-            #[allow(unused)]
-            written_len += size_of::<u32>();
-        }),
+        }
+        _ if arg.typ == "array" => quote! {&[u8]},
+        _ if arg.typ == "fd" => quote! {Fd},
+        _ => arg_type(ctx, interface, arg),
     }
 }
 
-fn parse_args(arg: &wayland_protocol_scanner::Arg) -> Option<TokenStream> {
-    let arg_name = ident!("{}", arg.name; Some(Case::SnakeCase));
-    let arg_typ = ident!("{}", arg.typ; Some(Case::CamelCase));
+fn send_arg(arg: &wayland_protocol_scanner::Arg) -> TokenStream {
+    let arg_name = ident!("{}",arg.name; None);
+    if is_untyped_new_id(arg) {
+        return quote! {
+            writer.put_string(Some(T::NAME));
+            writer.put_uint(version);
+            writer.put_new_id(#arg_name.object_id());
+        };
+    }
+    if is_enum_arg(arg) {
+        return quote! {writer.put_uint(#arg_name.into());};
+    }
     match &arg.typ[..] {
-        "fixed" => Some(quote! {
-            let #arg_name: f32 = 0.0;
-            warn!("Fixed value has not been implemented");
-        }),
-        "string" => Some(quote! {
-            parsed_len += size_of::<u32>();
-            let start = parsed_len - size_of::<u32>();
-
-            let raw_ptr = msg_body[start..parsed_len].as_ptr() as *const u32;
-            let str_len = unsafe{
-                *raw_ptr
-            };
-            let str_len = (str_len as f64 / 4.0).ceil() as usize * 4;
-            parsed_len += str_len;
-
-            let src_ptr = msg_body[(start + size_of::<u32>())..parsed_len].as_ptr();
-            let mut tmp_ptr = Vec::with_capacity(str_len);
-            unsafe {
-                tmp_ptr.set_len(str_len);
-                std::ptr::copy(src_ptr, tmp_ptr.as_mut_ptr(), str_len);
+        "object" if is_nullable(arg) => quote! {
+            writer.put_object(#arg_name.map_or(0, |object| object.object_id));
+        },
+        // wl_display.error refers to objects of any interface by their id.
+        "object" if arg.interface.is_none() => quote! {writer.put_object(#arg_name);},
+        "object" => quote! {writer.put_object(#arg_name.object_id);},
+        "new_id" => quote! {writer.put_new_id(#arg_name.object_id);},
+        "string" if is_nullable(arg) => quote! {writer.put_string(#arg_name.as_deref());},
+        "string" => quote! {writer.put_string(Some(&#arg_name));},
+        "fixed" => quote! {writer.put_fixed(#arg_name);},
+        "array" => quote! {writer.put_array(#arg_name);},
+        "fd" => quote! {writer.put_fd(#arg_name);},
+        "int" => quote! {writer.put_int(#arg_name);},
+        _ => quote! {writer.put_uint(#arg_name);},
+    }
+}
+
+fn parse_args(
+    ctx: &Context,
+    interface: &Interface,
+    arg: &wayland_protocol_scanner::Arg,
+) -> TokenStream {
+    let arg_name = ident!("{}", arg.name; Some(Case::SnakeCase));
+    if is_enum_arg(arg) {
+        let enum_typ = arg_type(ctx, interface, arg);
+        return quote! {
+            let #arg_name = #enum_typ::from(reader.uint()?);
+        };
+    }
+    if let Some(proxy_interface) = proxy_interface(arg) {
+        let proxy_typ = ident!("{}", proxy_interface; Some(Case::CamelCase));
+        let proxy_typ = ctx.type_path(proxy_interface, proxy_typ);
+        // Objects announced by a new_id are created by the server and have to be
+        // registered before later events can be dispatched to them.
+        if is_typed_new_id(arg) {
+            return quote! {
+                let #arg_name = self.client.insert_server_obj::<#proxy_typ>(reader.new_id()?, self.version);
             };
-            let #arg_name = std::str::from_utf8(&tmp_ptr).unwrap().trim_matches('\0').to_string();
-        }),
-        "array" => Some(quote! {
-            let #arg_name: Vec<u32> = Vec::new();
-            warn!("Array value has not been implemented");
-        }),
-        _ => Some(quote! {
-            parsed_len += size_of::<#arg_typ>();
-            let start = parsed_len - size_of::<#arg_typ>();
-
-            let raw_ptr = msg_body[start..parsed_len].as_ptr() as *const #arg_typ;
-            let #arg_name = unsafe{
-                *raw_ptr
+        }
+        let proxy = quote! {
+            self.client
+                .get_obj(object_id)
+                .and_then(|obj| #proxy_typ::from_enum(&obj))
+                .unwrap_or_else(|| #proxy_typ::new(object_id, self.version, self.client.clone()))
+        };
+        if is_nullable(arg) {
+            return quote! {
+                let #arg_name = reader.object()?.map(|object_id| #proxy);
             };
-        }),
+        }
+        return quote! {
+            let object_id = reader.object()?.ok_or(WireError::UnexpectedNull)?;
+            let #arg_name = #proxy;
+        };
     }
+    read_value(arg)
 }
 
-fn generate_code_for_interface(interface: &Interface) -> TokenStream {
-    let struct_name = ident!("{}", interface.name; Some(Case::CamelCase));
+// Reads an argument that is neither an enum nor refers to an object.
+fn read_value(arg: &Arg) -> TokenStream {
+    let arg_name = ident!("{}", arg.name; Some(Case::SnakeCase));
+    match &arg.typ[..] {
+        "string" if is_nullable(arg) => quote! {let #arg_name = reader.string()?;},
+        "string" => quote! {let #arg_name = reader.string()?.ok_or(WireError::UnexpectedNull)?;},
+        "fixed" => quote! {let #arg_name = reader.fixed()?;},
+        "array" => quote! {let #arg_name = reader.array()?;},
+        "fd" => quote! {let #arg_name = reader.fd()?;},
+        "int" => quote! {let #arg_name = reader.int()?;},
+        _ => quote! {let #arg_name = reader.uint()?;},
+    }
+}
 
-    let mut req_op_code: i32 = -1;
-    let send_req_functions = interface.items.iter().filter_map(|msg| match msg {
-        InterfaceChild::Request(req) => {
-            req_op_code += 1;
-            let args = generate_arguments!(req);
-            let function_name = ident!("{}", &req.name; None);
-
-            let add_raw_size = req.items.iter().filter_map(|child| {
-                match child {
-                    EventOrRequestField::Arg(arg) => {
-                        add_arg_size(arg)
-                    }
-                    _ => { None }
+// Descriptions are plain text, bit ranges like `[31:0]` would otherwise be
+// taken for intra-doc links.
+fn escape_doc(line: &str) -> String {
+    line.replace('[', "\\[").replace(']', "\\]")
+}
+
+// rustdoc renders lines indented by four spaces as code blocks, and protocol
+// descriptions are indented to match the XML, so every line is trimmed.
+fn doc_lines(summary: &str, body: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    if !summary.trim().is_empty() {
+        lines.push(escape_doc(summary.trim()));
+    }
+    let mut blank = !lines.is_empty();
+    for line in body.lines().map(str::trim) {
+        if line.is_empty() {
+            blank = !lines.is_empty();
+            continue;
+        }
+        if blank {
+            lines.push(String::new());
+            blank = false;
+        }
+        lines.push(escape_doc(line));
+    }
+    lines
+}
+
+fn description_lines(description: Option<&Description>) -> Vec<String> {
+    match description {
+        Some(description) => doc_lines(&description.summary, &description.body),
+        None => Vec::new(),
+    }
+}
+
+fn since_lines(lines: &mut Vec<String>, since: Option<u32>) {
+    if let Some(since) = since {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("Available since version {}.", since));
+    }
+}
+
+fn doc_attrs(lines: &[String]) -> TokenStream {
+    let lines = lines.iter().map(|line| {
+        if line.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", line)
+        }
+    });
+    quote! {
+        #(#[doc = #lines])*
+    }
+}
+
+fn arg_doc(arg: &Arg) -> TokenStream {
+    let mut lines = doc_lines(&arg.summary, "");
+    lines.extend(description_lines(arg.description.as_ref()));
+    doc_attrs(&lines)
+}
+
+fn request_doc(req: &Request) -> TokenStream {
+    message_doc(req.description(), req.args(), req.since)
+}
+
+// Docs of the methods sending a message, which list the parameters.
+fn message_doc<'a>(
+    description: Option<&Description>,
+    args: impl Iterator<Item = &'a Arg>,
+    since: Option<u32>,
+) -> TokenStream {
+    let mut lines = description_lines(description);
+    let args: Vec<(String, &str)> = args
+        .filter(|arg| !is_typed_new_id(arg))
+        .map(|arg| {
+            if is_untyped_new_id(arg) {
+                (String::from("version"), "version of the interface to use")
+            } else {
+                (escape_name(&arg.name), arg.summary.trim())
+            }
+        })
+        .collect();
+    if args.iter().any(|(_, summary)| !summary.is_empty()) {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(String::from("# Arguments"));
+        lines.push(String::new());
+        for (name, summary) in args {
+            lines.push(format!("* `{}` - {}", name, summary));
+        }
+    }
+    since_lines(&mut lines, since);
+    doc_attrs(&lines)
+}
+
+fn event_doc(ev: &Event) -> TokenStream {
+    description_doc(ev.description(), ev.since)
+}
+
+fn description_doc(description: Option<&Description>, since: Option<u32>) -> TokenStream {
+    let mut lines = description_lines(description);
+    since_lines(&mut lines, since);
+    doc_attrs(&lines)
+}
+
+fn entry_name(entry: &Entry, case: Case) -> Ident {
+    let name = match case {
+        Case::CamelCase => entry.name.to_camel_case(),
+        Case::SnakeCase => entry.name.to_shouty_snake_case(),
+    };
+    // Entries such as wl_output.transform's `90` are not valid identifiers.
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        construct_indent_from_string(&format!("_{}", name))
+    } else {
+        construct_indent_from_string(&name)
+    }
+}
+
+fn entry_value(interface: &Interface, enumeration: &Enum, entry: &Entry) -> u32 {
+    entry.parse_value().unwrap_or_else(|| {
+        panic!(
+            "{}.{}.{}: invalid value `{}`",
+            interface.name, enumeration.name, entry.name, entry.value
+        )
+    })
+}
+
+fn entry_doc(entry: &Entry) -> TokenStream {
+    let mut lines = doc_lines(&entry.summary, "");
+    lines.extend(description_lines(entry.description.as_ref()));
+    since_lines(&mut lines, entry.since);
+    doc_attrs(&lines)
+}
+
+fn generate_code_for_enum(interface: &Interface, enumeration: &Enum) -> TokenStream {
+    let enum_name = ident!("{}_{}", interface.name, enumeration.name; Some(Case::CamelCase));
+    let mut lines = description_lines(enumeration.description());
+    since_lines(&mut lines, enumeration.since);
+    let doc = doc_attrs(&lines);
+
+    if enumeration.bitfield {
+        let flags = enumeration.entries().map(|entry| {
+            let doc = entry_doc(entry);
+            let flag_name = entry_name(entry, Case::SnakeCase);
+            let value = entry_value(interface, enumeration, entry);
+            quote! {
+                #doc
+                pub const #flag_name: #enum_name = #enum_name(#value);
+            }
+        });
+        return quote! {
+            #doc
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+            pub struct #enum_name(u32);
+            impl #enum_name {
+                #(#flags)*
+
+                pub fn empty() -> #enum_name {
+                    #enum_name(0)
                 }
-            });
-            let send_args = req.items.iter().filter_map(|child| {
-                match child {
-                    EventOrRequestField::Arg(arg) => {
-                        send_arg(arg)
-                    }
-                    _ => { None }
+                /// Keeps bits this version of the protocol does not define.
+                pub fn from_bits(bits: u32) -> #enum_name {
+                    #enum_name(bits)
                 }
-            });
+                pub fn bits(self) -> u32 {
+                    self.0
+                }
+                pub fn is_empty(self) -> bool {
+                    self.0 == 0
+                }
+                pub fn contains(self, other: #enum_name) -> bool {
+                    self.0 & other.0 == other.0
+                }
+                pub fn insert(&mut self, other: #enum_name) {
+                    self.0 |= other.0;
+                }
+                pub fn remove(&mut self, other: #enum_name) {
+                    self.0 &= !other.0;
+                }
+            }
+            impl std::ops::BitOr for #enum_name {
+                type Output = #enum_name;
+                fn bitor(self, other: #enum_name) -> #enum_name {
+                    #enum_name(self.0 | other.0)
+                }
+            }
+            impl std::ops::BitOrAssign for #enum_name {
+                fn bitor_assign(&mut self, other: #enum_name) {
+                    self.0 |= other.0;
+                }
+            }
+            impl std::ops::BitAnd for #enum_name {
+                type Output = #enum_name;
+                fn bitand(self, other: #enum_name) -> #enum_name {
+                    #enum_name(self.0 & other.0)
+                }
+            }
+            impl From<u32> for #enum_name {
+                fn from(bits: u32) -> #enum_name {
+                    #enum_name(bits)
+                }
+            }
+            impl From<#enum_name> for u32 {
+                fn from(flags: #enum_name) -> u32 {
+                    flags.0
+                }
+            }
+        };
+    }
 
-            Some(quote! {
-                pub fn #function_name(&self, #(#args),*) {
-                    #[allow(unused)]
-                    let mut raw_size = 8;
-                    #(#add_raw_size)*
-                    let mut send_buffer: Vec<u8> = vec![0; raw_size];
-                    let mut send_fd = vec![0; 16];
-
-                    #[allow(unused)]
-                    let mut send_fd_num = 0;
-                    unsafe {
-                        std::ptr::copy(&self.object_id as *const u32, &mut send_buffer[0] as *mut u8 as *mut u32, 1);
-                        let op_code_and_length: u32 = ((raw_size as u32) << 16) + (#req_op_code as u32);
-                        std::ptr::copy(&op_code_and_length as *const u32, &mut send_buffer[size_of::<u32>()] as *mut u8 as *mut u32, 1);
-                    }
+    let entries: Vec<(TokenStream, Ident, u32)> = enumeration
+        .entries()
+        .map(|entry| {
+            (
+                entry_doc(entry),
+                entry_name(entry, Case::CamelCase),
+                entry_value(interface, enumeration, entry),
+            )
+        })
+        .collect();
+    let allow_digits = if entries
+        .iter()
+        .any(|(_, name, _)| name.to_string().starts_with('_'))
+    {
+        quote! {#[allow(non_camel_case_types)]}
+    } else {
+        quote! {}
+    };
+    let variants = entries.iter().map(|(doc, name, _)| quote! {#doc #name,});
+    let from_values = entries
+        .iter()
+        .map(|(_, name, value)| quote! {#value => #enum_name::#name,});
+    let into_values = entries
+        .iter()
+        .map(|(_, name, value)| quote! {#enum_name::#name => #value,});
+    quote! {
+        #doc
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #allow_digits
+        pub enum #enum_name {
+            #(#variants)*
+            /// A value this version of the protocol does not define.
+            Unrecognized(u32),
+        }
+        impl From<u32> for #enum_name {
+            fn from(value: u32) -> #enum_name {
+                match value {
+                    #(#from_values)*
+                    value => #enum_name::Unrecognized(value),
+                }
+            }
+        }
+        impl From<#enum_name> for u32 {
+            fn from(value: #enum_name) -> u32 {
+                match value {
+                    #(#into_values)*
+                    #enum_name::Unrecognized(value) => value,
+                }
+            }
+        }
+    }
+}
 
-                    #[allow(unused)]
-                    let mut written_len: usize = 8;
-                    #(#send_args)*
-                    unsafe {
-                        send_fd.set_len(send_fd_num);
-                    }
-                    self.socket.send(&send_buffer, &send_fd);
+fn generate_code_for_interface(ctx: &Context, interface: &Interface) -> TokenStream {
+    let struct_name = ident!("{}", interface.name; Some(Case::CamelCase));
+
+    let interface_name = &interface.name;
+    let send_req_functions = interface.requests().enumerate().map(|(op_code, req)| {
+        let op_code = op_code as u16;
+        let args = req.args().filter(|arg| !is_typed_new_id(arg)).map(|arg| {
+            if is_untyped_new_id(arg) {
+                return quote! {version: Uint};
+            }
+            let arg_name = ident!("{}", arg.name; None);
+            let arg_typ = param_type(ctx, interface, arg);
+            quote! {#arg_name: #arg_typ}
+        });
+        let new_id = req.args().find(|arg| arg.typ == "new_id");
+        let (generics, new_proxy, ret_typ, ret_value) = match new_id {
+            Some(arg) => {
+                let arg_name = ident!("{}", arg.name; None);
+                // Objects created by a request inherit the version of their parent.
+                let (generics, arg_typ, version) = if is_untyped_new_id(arg) {
+                    (quote! {<T: Interface>}, quote! {T}, quote! {version})
+                } else {
+                    (quote! {}, arg_type(ctx, interface, arg), quote! {self.version})
+                };
+                (
+                    generics,
+                    quote! {let #arg_name = self.client.new_obj::<#arg_typ>(#version);},
+                    arg_typ,
+                    quote! {#arg_name},
+                )
+            }
+            None => (quote! {}, quote! {}, quote! {()}, quote! {}),
+        };
+        let since = req.since.unwrap_or(1);
+        let (ret_typ, check_version, ret_value) = if since > 1 {
+            let request_name = &req.name;
+            let error = quote! {
+                VersionError {
+                    interface: #interface_name,
+                    request: #request_name,
+                    since: #since,
+                    version: self.version,
                 }
-            })
+            };
+            let (err_typ, error) = if req.is_destructor() {
+                (
+                    quote! {DestroyError<#struct_name>},
+                    quote! {DestroyError { error: #error, proxy: self }},
+                )
+            } else {
+                (quote! {VersionError}, error)
+            };
+            (
+                quote! {-> Result<#ret_typ, #err_typ>},
+                quote! {
+                    if self.version < #since {
+                        return Err(#error);
+                    }
+                },
+                if new_id.is_some() {
+                    quote! {Ok(#ret_value)}
+                } else {
+                    quote! {Ok(())}
+                },
+            )
+        } else if new_id.is_some() {
+            (quote! {-> #ret_typ}, quote! {}, ret_value)
+        } else {
+            (quote! {}, quote! {}, ret_value)
+        };
+        // Ids allocated by the client stay reserved until the server acknowledges
+        // with delete_id. They are marked before sending, so that a delete_id
+        // handled in between by the event thread cannot be undone.
+        let (receiver, destroy) = if req.is_destructor() {
+            (quote! {self}, quote! {self.client.destroy_obj(self.object_id);})
+        } else {
+            (quote! {&self}, quote! {})
+        };
+        let function_name = ident!("{}", &req.name; None);
+        let doc = request_doc(req);
+
+        let send_args = req.args().map(send_arg);
+        let writer = if req.args().next().is_some() {
+            quote! {let mut writer = MessageWriter::new(self.object_id, #op_code);}
+        } else {
+            quote! {let writer = MessageWriter::new(self.object_id, #op_code);}
+        };
+
+        quote! {
+            #doc
+            pub fn #function_name #generics(#receiver, #(#args),*) #ret_typ {
+                #check_version
+                #destroy
+                #new_proxy
+                #writer
+                #(#send_args)*
+                let (send_buffer, send_fd) = writer.finish();
+                self.client.socket().send(&send_buffer, &send_fd);
+                #ret_value
+            }
         }
-        _ => None
     });
 
-    let mut ev_op_code: i32 = -1;
-    let parse_ev = interface.items.iter().filter_map(|msg| match msg {
-        InterfaceChild::Event(ev) => {
-            ev_op_code += 1;
-            let op_code = ev_op_code as u16;
-
-            let ev_name_str = format!(
-                "{}{}Event",
-                interface.name.to_camel_case(),
-                ev.name.to_camel_case()
-            );
-            let ev_interface_name = ident!("{}Event", interface.name; Some(Case::CamelCase));
-            let ev_name = ident!("{}{}Event", interface.name, ev.name; Some(Case::CamelCase));
-
-            let parse_args = ev.items.iter().filter_map(|field| match field {
-                EventOrRequestField::Arg(arg) => parse_args(arg),
-                _ => None,
-            });
-            let arg_names = ev.items.iter().filter_map(|field| match field {
-                EventOrRequestField::Arg(arg) => {
-                    let arg_name = ident!("{}", arg.name; Some(Case::SnakeCase));
-                    Some(quote! {#arg_name})
-                }
-                _ => None,
-            });
-            Some(quote! {
-                #op_code => {
-                    info!("Receive event {}", #ev_name_str);
-
-                    #[allow(unused)]
-                    let mut parsed_len: usize = 0;
-                    #(#parse_args)*
-                    Event::#ev_interface_name(#ev_interface_name::#ev_name(#ev_name {
-                        sender_id,
-                        #(#arg_names),*
-                    }))
-                }
+    let parse_ev = interface.events().enumerate().map(|(op_code, ev)| {
+        let op_code = op_code as u16;
+
+        let ev_name_str = format!(
+            "{}{}Event",
+            interface.name.to_camel_case(),
+            ev.name.to_camel_case()
+        );
+        let ev_interface_name = ident!("{}Event", interface.name; Some(Case::CamelCase));
+        let ev_name = ident!("{}{}Event", interface.name, ev.name; Some(Case::CamelCase));
+
+        let parse_args = ev.args().map(|arg| parse_args(ctx, interface, arg));
+        let reader = if ev.args().any(|arg| arg.typ == "fd") {
+            quote! {
+                let mut fds = self.client.socket().received_fds();
+                let mut reader = MessageReader::with_fds(msg_body, &mut fds);
+            }
+        } else if ev.args().next().is_some() {
+            quote! {let mut reader = MessageReader::new(msg_body);}
+        } else {
+            quote! {}
+        };
+        let arg_names = ev.items.iter().filter_map(|field| match field {
+            EventOrRequestField::Arg(arg) => {
+                let arg_name = ident!("{}", arg.name; Some(Case::SnakeCase));
+                Some(quote! {#arg_name})
+            }
+            _ => None,
+        });
+        let event = quote! {
+            #ev_interface_name::#ev_name(#ev_name {
+                sender_id,
+                #(#arg_names),*
             })
+        };
+        let event = if ctx.core {
+            quote! {Event::#ev_interface_name(#event)}
+        } else {
+            quote! {Event::Extension(Box::new(#event))}
+        };
+        quote! {
+            #op_code => {
+                info!("Receive event {}", #ev_name_str);
+
+                #reader
+                #(#parse_args)*
+                Ok(#event)
+            }
         }
-        _ => None
     });
+    let parse_event = if interface.events().next().is_some() {
+        quote! {
+            match op_code {
+                #(#parse_ev)*
+                _ => Err(WireError::UnknownOpcode(op_code)),
+            }
+        }
+    } else {
+        quote! {Err(WireError::UnknownOpcode(op_code))}
+    };
+    // Proxies for other protocols cannot be added to saiko's WlObject, so they
+    // are stored behind the ExtensionObject trait and found again by downcasting.
+    let (to_enum, from_enum, impl_extension) = if ctx.core {
+        (
+            quote! {WlObject::#struct_name(self)},
+            quote! {
+                match obj {
+                    WlObject::#struct_name(item) => Some(item.clone()),
+                    _ => None,
+                }
+            },
+            quote! {},
+        )
+    } else {
+        (
+            quote! {WlObject::Extension(Arc::new(self))},
+            quote! {
+                match obj {
+                    WlObject::Extension(item) => item.as_any().downcast_ref::<#struct_name>().cloned(),
+                    _ => None,
+                }
+            },
+            quote! {
+                impl ExtensionObject for #struct_name {
+                    fn parse_event(&self, sender_id: u32, op_code: u16, msg_body: &[u8]) -> Result<Event, WireError> {
+                        #struct_name::parse_event(self, sender_id, op_code, msg_body)
+                    }
+                    fn as_any(&self) -> &dyn Any {
+                        self
+                    }
+                    fn descriptor(&self) -> &'static InterfaceDescriptor {
+                        <#struct_name as Interface>::DESCRIPTOR
+                    }
+                }
+            },
+        )
+    };
+    let struct_doc = doc_attrs(&description_lines(interface.description()));
+    let interface_version = interface.version;
+    let descriptor_name = descriptor_name(&interface.name);
+    let enums = interface
+        .enums()
+        .map(|enumeration| generate_code_for_enum(interface, enumeration));
     quote! {
+        #struct_doc
         #[derive(Clone)]
         pub struct #struct_name {
             #[allow(dead_code)]
             pub object_id: u32,
+            version: u32,
             #[allow(dead_code)]
-            pub socket: Arc<WaylandSocket>,
+            pub client: Client,
         }
         impl WlRawObject for #struct_name {
-            fn new(object_id: u32, socket: Arc<WaylandSocket>) -> #struct_name {
-                #struct_name { object_id, socket }
+            fn new(object_id: u32, version: u32, client: Client) -> #struct_name {
+                #struct_name { object_id, version, client }
+            }
+            fn object_id(&self) -> u32 {
+                self.object_id
             }
             fn to_enum(self) -> WlObject {
-                WlObject::#struct_name(self)
+                #to_enum
             }
+            fn from_enum(obj: &WlObject) -> Option<#struct_name> {
+                #from_enum
+            }
+        }
+        #impl_extension
+        impl Interface for #struct_name {
+            const NAME: &'static str = #interface_name;
+            const VERSION: u32 = #interface_version;
+            const DESCRIPTOR: &'static InterfaceDescriptor = &#descriptor_name;
         }
         impl #struct_name {
-            fn parse_event(sender_id: u32, op_code: u16, msg_body: Vec<u8>) -> Event {
-                match op_code {
-                    #(#parse_ev)*
-                    _ => panic!("Unknown event")
-                }
+            pub fn version(&self) -> u32 {
+                self.version
+            }
+            #[allow(unused_variables)]
+            fn parse_event(&self, sender_id: u32, op_code: u16, msg_body: &[u8]) -> Result<Event, WireError> {
+                #parse_event
             }
             #(#send_req_functions)*
         }
+        #(#enums)*
     }
 }
 
-fn generate_code_for_wayland_enums() -> TokenStream {
-    let enum_interface_names = PROTOCOL.items.iter().filter_map(|item| match item {
+fn generate_code_for_wayland_enums(ctx: &Context) -> TokenStream {
+    let enum_interface_names = ctx.protocol.items.iter().filter_map(|item| match item {
         ProtocolChild::Interface(interface) => {
             let interface_name = ident!("{}", interface.name; Some(Case::CamelCase));
             Some(quote! {#interface_name(#interface_name)})
         }
         _ => None,
     });
-    let enum_event_names = PROTOCOL.items.iter().filter_map(|item| match item {
+    let enum_event_names = ctx.protocol.items.iter().filter_map(|item| match item {
         ProtocolChild::Interface(interface) => {
             let event_name = ident!("{}Event", interface.name; Some(Case::CamelCase));
             Some(quote! {#event_name(#event_name)})
         }
         _ => None,
     });
-    let wl_event_enums = PROTOCOL.items.iter().filter_map(|item| match item {
+    let wl_event_enums = ctx.protocol.items.iter().filter_map(|item| match item {
         ProtocolChild::Interface(interface) => {
             let event_enum_name = ident!("{}Event", interface.name; Some(Case::CamelCase));
+            let event_enum_doc = format!(" Events sent by `{}` objects.", interface.name);
             let event_structs = interface.items.iter().filter_map(|ev| match ev {
                 InterfaceChild::Event(ev) => {
-                    let ev_struct_enum_name = ident!("{}{}Event", interface.name, ev.name; Some(Case::CamelCase));
-                    let event_fields = generate_arguments!(ev);
+                    let ev_struct_enum_name =
+                        ident!("{}{}Event", interface.name, ev.name; Some(Case::CamelCase));
+                    let doc = event_doc(ev);
+                    let event_field_docs = ev.args().map(arg_doc);
+                    let event_fields = generate_arguments!(ctx, interface, ev);
                     Some(quote! {
+                        #doc
                         pub struct #ev_struct_enum_name {
+                            /// Id of the object that sent the event.
                             #[allow(dead_code)]
                             pub sender_id: u32,
-                            #(#[allow(dead_code)]pub #event_fields),*
+                            #(#event_field_docs #[allow(dead_code)]pub #event_fields),*
                         }
                     })
                 }
-                _ => None
+                _ => None,
             });
             let event_struct_enum_names = interface.items.iter().filter_map(|ev| match ev {
                 InterfaceChild::Event(ev) => {
-                    let ev_struct_enum_name = ident!("{}{}Event", interface.name, ev.name; Some(Case::CamelCase));
+                    let ev_struct_enum_name =
+                        ident!("{}{}Event", interface.name, ev.name; Some(Case::CamelCase));
+                    let doc = doc_attrs(&doc_lines(
+                        ev.description().map_or("", |description| &description.summary),
+                        "",
+                    ));
                     Some(quote! {
+                        #doc
                         #ev_struct_enum_name(#ev_struct_enum_name)
                     })
                 }
-                _ => None
+                _ => None,
             });
             Some(quote! {
                 #(#event_structs)*
+                #[doc = #event_enum_doc]
                 pub enum #event_enum_name {
                     #(#event_struct_enum_names),*
                 }
@@ -321,7 +923,12 @@ fn generate_code_for_wayland_enums() -> TokenStream {
         }
         _ => None,
     });
-    let impl_wl_get_obj = PROTOCOL.items.iter().filter_map(|item| match item {
+    if !ctx.core {
+        return quote! {
+            #(#wl_event_enums)*
+        };
+    }
+    let impl_wl_get_obj = ctx.protocol.items.iter().filter_map(|item| match item {
         ProtocolChild::Interface(interface) => {
             let interface_name = ident!("{}", interface.name; Some(Case::CamelCase));
             let get_function_name = ident!("try_get_{}", interface.name; Some(Case::SnakeCase));
@@ -339,10 +946,15 @@ fn generate_code_for_wayland_enums() -> TokenStream {
     });
     quote! {
         pub enum WlObject {
-            #(#enum_interface_names),*
+            #(#enum_interface_names,)*
+            /// An object of a protocol generated outside of saiko.
+            Extension(Arc<dyn ExtensionObject>),
         }
         pub enum Event {
-            #(#enum_event_names),*
+            #(#enum_event_names,)*
+            /// An event of a protocol generated outside of saiko, to be downcast to
+            /// the event enum of its interface.
+            Extension(Box<dyn Any + Send + Sync>),
         }
         #(#wl_event_enums)*
         impl WlObject {
@@ -351,125 +963,255 @@ fn generate_code_for_wayland_enums() -> TokenStream {
     }
 }
 
-pub fn generate_wayland_protocol_code() -> String {
-    let codes_for_every_interface = PROTOCOL.items.iter().filter_map(|item| match item {
-        ProtocolChild::Interface(interface) => {
-            Some(generate_code_for_interface(interface))
+fn descriptor_name(interface: &str) -> Ident {
+    construct_indent_from_string(&format!("{}_INTERFACE", interface.to_shouty_snake_case()))
+}
+
+fn generate_message_descriptor<'a>(
+    ctx: &Context,
+    name: &str,
+    since: Option<u32>,
+    args: impl Iterator<Item = &'a Arg>,
+) -> TokenStream {
+    let mut signature = String::new();
+    let mut types = Vec::new();
+    for arg in args {
+        signature.push_str(&arg.signature());
+        if let Some(interface) = proxy_interface(arg) {
+            let descriptor = ctx.type_path(interface, descriptor_name(interface));
+            types.push(quote! {Some(&#descriptor)});
+        } else {
+            // One entry for every value of `sun` as well.
+            let values = arg.signature().trim_start_matches('?').len();
+            types.extend((0..values).map(|_| quote! {None}));
+        }
+    }
+    let since = since.unwrap_or(1);
+    quote! {
+        MessageDescriptor {
+            name: #name,
+            since: #since,
+            signature: #signature,
+            types: &[#(#types),*],
+        }
+    }
+}
+
+// Statics rather than consts, as interfaces refer to each other (and to
+// themselves) through the types of their messages.
+fn generate_descriptors(ctx: &Context) -> TokenStream {
+    let descriptors = ctx.protocol.interfaces().map(|interface| {
+        let descriptor = descriptor_name(&interface.name);
+        let name = &interface.name;
+        let version = interface.version;
+        let requests = interface
+            .requests()
+            .map(|req| generate_message_descriptor(ctx, &req.name, req.since, req.args()));
+        let events = interface
+            .events()
+            .map(|ev| generate_message_descriptor(ctx, &ev.name, ev.since, ev.args()));
+        quote! {
+            pub static #descriptor: InterfaceDescriptor = InterfaceDescriptor {
+                name: #name,
+                version: #version,
+                requests: &[#(#requests),*],
+                events: &[#(#events),*],
+            };
         }
-        _ => None
     });
-    let code_for_wayland_enums = generate_code_for_wayland_enums();
+    let interfaces = ctx
+        .protocol
+        .interfaces()
+        .map(|interface| descriptor_name(&interface.name));
+    quote! {
+        #(#descriptors)*
+        /// The descriptors of every interface of the protocol.
+        pub static INTERFACES: &[&InterfaceDescriptor] = &[#(&#interfaces),*];
+    }
+}
+
+fn generate_code_for_protocol(ctx: &Context) -> TokenStream {
+    let codes_for_every_interface = ctx
+        .protocol
+        .interfaces()
+        .map(|interface| generate_code_for_interface(ctx, interface));
+    let code_for_wayland_enums = generate_code_for_wayland_enums(ctx);
+    let descriptors = generate_descriptors(ctx);
+    quote! {
+        #(#codes_for_every_interface)*
+        #code_for_wayland_enums
+        #descriptors
+    }
+}
+
+#[derive(Debug)]
+pub enum GenerateError {
+    Parse(ParseError),
+    Invalid(Vec<Diagnostic>),
+    InvalidModule(String),
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenerateError::Parse(err) => write!(f, "{}", err),
+            GenerateError::Invalid(diagnostics) => {
+                let diagnostics: Vec<String> = diagnostics
+                    .iter()
+                    .map(|diagnostic| diagnostic.to_string())
+                    .collect();
+                write!(f, "{}", diagnostics.join("\n"))
+            }
+            GenerateError::InvalidModule(module) => {
+                write!(f, "`{}` is not a module path", module)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+impl From<ParseError> for GenerateError {
+    fn from(err: ParseError) -> GenerateError {
+        GenerateError::Parse(err)
+    }
+}
+
+/// Generates the contents of a module with proxies for a protocol that builds
+/// on the core wayland protocol, for use with `saiko::client::Client`.
+///
+/// The code refers to saiko as `::saiko`, so the crate including it has to
+/// depend on saiko directly.
+pub fn generate_protocol_code(protocol: &Protocol) -> Result<String, GenerateError> {
+    generate_protocol_code_with_dependencies(protocol, &[])
+}
+
+/// Generates the module of a protocol like `generate_protocol_code`, for
+/// protocols that also build on other extension protocols.
+pub fn generate_protocol_code_with_dependencies(
+    protocol: &Protocol,
+    dependencies: &[Dependency],
+) -> Result<String, GenerateError> {
+    let ctx = Context::extension(protocol, dependencies, false)?;
+    let code_for_protocol = generate_code_for_protocol(&ctx);
+    let code = quote! {
+        #[allow(unused_imports)]
+        use ::saiko::client::Client;
+        #[allow(unused_imports)]
+        use ::saiko::descriptor::{InterfaceDescriptor, MessageDescriptor};
+        #[allow(unused_imports)]
+        use ::saiko::error::{DestroyError, VersionError, WireError};
+        #[allow(unused_imports)]
+        use ::saiko::log::info;
+        #[allow(unused_imports)]
+        use ::saiko::types::Fixed;
+        #[allow(unused_imports)]
+        use ::saiko::wayland::{Event, ExtensionObject, Interface, WlObject, WlRawObject};
+        #[allow(unused_imports)]
+        use ::saiko::wire::{MessageReader, MessageWriter};
+        #[allow(unused_imports)]
+        use std::any::Any;
+        #[allow(unused_imports)]
+        use std::os::unix::io::{OwnedFd, RawFd};
+        #[allow(unused_imports)]
+        use std::sync::Arc;
+
+        #[allow(dead_code)]
+        type Uint=u32;
+        #[allow(dead_code)]
+        type Int=i32;
+        #[allow(dead_code)]
+        type Fd=RawFd;
+        #[allow(dead_code)]
+        type Object=u32;
+        #[allow(dead_code)]
+        type Array=Vec<u8>;
+
+        #code_for_protocol
+    };
+
+    Ok(code.to_string())
+}
+
+/// Reads a protocol description and generates its module like
+/// `generate_protocol_code`, for build scripts which write the code to
+/// `OUT_DIR` and `include!` it.
+pub fn generate_protocol_code_from_file<P: AsRef<Path>>(path: P) -> Result<String, GenerateError> {
+    let protocol = wayland_protocol_scanner::parse_protocol_from_file(path)?;
+    generate_protocol_code(&protocol)
+}
+
+pub fn generate_wayland_protocol_code() -> String {
+    let ctx = Context::core(false);
+    let code_for_protocol = generate_code_for_protocol(&ctx);
     let parse_event_for_interface = PROTOCOL.items.iter().filter_map(|item| match item {
         ProtocolChild::Interface(interface) => {
             let interface_name = ident!("{}", interface.name; Some(Case::CamelCase));
             Some(quote! {
-                WlObject::#interface_name(_obj) => #interface_name::parse_event(sender_id, op_code, msg_body)
+                WlObject::#interface_name(obj) => obj.parse_event(sender_id, op_code, msg_body)
             })
         }
         _ => None
     });
 
+    let descriptor_for_interface = PROTOCOL.interfaces().map(|interface| {
+        let interface_name = ident!("{}", interface.name; Some(Case::CamelCase));
+        quote! {
+            WlObject::#interface_name(_) => #interface_name::DESCRIPTOR
+        }
+    });
+
     let code = quote! {
-        use super::socket::*;
-        use crate::unix_socket::UnixSocket;
+        use crate::client::Client;
+        use crate::descriptor::{InterfaceDescriptor, MessageDescriptor};
+        use crate::error::{DestroyError, VersionError, WireError};
+        use crate::types::Fixed;
+        use crate::wire::{MessageReader, MessageWriter};
+        use std::any::Any;
+        use std::os::unix::io::{OwnedFd, RawFd};
         use std::sync::Arc;
-        use std::mem::transmute;
-        use std::mem::size_of;
 
-        type NewId=u32;
         type Uint=u32;
         type Int=i32;
-        type Fd=i32;
+        type Fd=RawFd;
         type Object=u32;
-        type Fixed=f32; // TODO: handle fixed value
-        type Array=Vec<u32>;
+        type Array=Vec<u8>;
 
 
-        #(#codes_for_every_interface)*
-        #code_for_wayland_enums
+        #code_for_protocol
 
-        #[repr(packed)]
-        struct EventHeaderPre {
-            pub sender_id: u32,
-            pub msg_size_and_op_code: u32,
-        }
-        #[repr(packed)]
-        pub struct EventHeader {
-            pub sender_id: u32,
-            pub msg_size: u16,
-            pub op_code: u16,
-        }
-        impl EventHeaderPre {
-            fn convert_to_event_header(self) -> EventHeader {
-                let msg_size = {
-                    let size = self.msg_size_and_op_code >> 16;
-                    if size % 4 == 0 {
-                        size as u16
-                    } else {
-                        (size as f64 / 4.0).ceil() as u16 * 4
-                    }
-                } - 8;
-                EventHeader {
-                    sender_id: self.sender_id,
-                    msg_size,
-                    op_code: self.msg_size_and_op_code as u16,
-                }
-            }
+        pub trait WlRawObject: Sized {
+            fn new(object_id: u32, version: u32, client: Client) -> Self;
+            fn object_id(&self) -> u32;
+            fn to_enum(self) -> WlObject;
+            fn from_enum(obj: &WlObject) -> Option<Self>;
         }
-        pub trait ReadEvent {
-            fn read_event(&mut self) -> Vec<(EventHeader, Vec<u8>)>;
+        pub trait Interface: WlRawObject + Clone {
+            const NAME: &'static str;
+            const VERSION: u32;
+            const DESCRIPTOR: &'static InterfaceDescriptor;
         }
-        impl ReadEvent for UnixSocket {
-            fn read_event(&mut self) -> Vec<(EventHeader, Vec<u8>)> {
-                let mut buffer: [u8; 1024] = [0; 1024];
-                let mut fds: [u8; 24] = [0; 24];
-                let (size, _) = self.read(&mut buffer, &mut fds);
-                if size == 1024 {
-                    warn!("Buffer is full");
-                }
-                let mut ret_value = Vec::new();
-                let mut read_size: usize = 0;
-                while read_size < size {
-                    let mut event_header: [u8; size_of::<EventHeaderPre>()] =
-                        [0; size_of::<EventHeaderPre>()];
-                    unsafe {
-                        std::ptr::copy(
-                            &buffer[read_size] as *const u8,
-                            event_header.as_mut_ptr(),
-                            size_of::<EventHeaderPre>(),
-                        );
-                    }
-                    let event_header = unsafe {
-                        transmute::<[u8; size_of::<EventHeaderPre>()], EventHeaderPre>(event_header)
-                            .convert_to_event_header()
-                    };
-                    let msg_size = event_header.msg_size as usize;
-                    let mut msg_body = vec![0; event_header.msg_size as usize];
-                    unsafe {
-                        std::ptr::copy(
-                            &buffer[read_size + size_of::<EventHeaderPre>()] as *const u8,
-                            msg_body.as_mut_ptr(),
-                            msg_size,
-                        );
-                    }
-                    ret_value.push((event_header, msg_body));
-                    read_size += size_of::<EventHeaderPre>() + msg_size;
-                }
-                return ret_value;
-            }
-        }
-        pub trait WlRawObject {
-            fn new(object_id: u32, socket: Arc<WaylandSocket>) -> Self;
-            fn to_enum(self) -> WlObject;
+        /// Proxies generated for other protocols, stored as `WlObject::Extension`.
+        pub trait ExtensionObject: Send + Sync {
+            fn parse_event(&self, sender_id: u32, op_code: u16, msg_body: &[u8]) -> Result<Event, WireError>;
+            fn as_any(&self) -> &dyn Any;
+            fn descriptor(&self) -> &'static InterfaceDescriptor;
         }
         impl WlObject {
-            pub fn parse_event(&self, sender_id: u32, op_code: u16, msg_body: Vec<u8>) -> Event {
+            pub fn descriptor(&self) -> &'static InterfaceDescriptor {
                 match self {
-                    #(#parse_event_for_interface),*
+                    #(#descriptor_for_interface,)*
+                    WlObject::Extension(obj) => obj.descriptor(),
+                }
+            }
+            pub fn parse_event(&self, sender_id: u32, op_code: u16, msg_body: &[u8]) -> Result<Event, WireError> {
+                match self {
+                    #(#parse_event_for_interface,)*
+                    WlObject::Extension(obj) => obj.parse_event(sender_id, op_code, msg_body),
                 }
             }
         }
     };
 
-    return code.to_string();
-}
\ No newline at end of file
+    code.to_string()
+}
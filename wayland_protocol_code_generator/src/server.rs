@@ -0,0 +1,513 @@
+use crate::{
+    arg_doc, arg_type, construct_ident_from_str_and_case, description_doc, description_lines,
+    descriptor_name, doc_attrs, escape_name, generate_code_for_enum, generate_descriptors,
+    is_enum_arg, is_nullable, is_typed_new_id, is_untyped_new_id, message_doc, param_type,
+    proxy_interface, read_value, send_arg, Case, Context, Dependency, GenerateError,
+};
+use heck::CamelCase;
+use proc_macro2::{Ident, TokenStream};
+use std::path::Path;
+use wayland_protocol_scanner::{Arg, Interface, Protocol, Request};
+
+// The server sees the same protocol from the other end: it sends events and
+// decodes requests, and the objects it manages are called resources.
+
+fn request_struct_name(interface: &Interface, req: &Request) -> Ident {
+    // Not escaped like other identifiers, `move` is fine in CamelCase.
+    construct_ident_from_str_and_case(
+        &format!("{}{}Request", interface.name, req.name),
+        Some(Case::CamelCase),
+    )
+}
+
+// The interface of a new_id without one is only known at runtime, so the
+// server decides which resource to create for it.
+fn field_type(ctx: &Context, interface: &Interface, arg: &Arg) -> TokenStream {
+    if is_untyped_new_id(arg) {
+        quote! {NewResource}
+    } else {
+        arg_type(ctx, interface, arg)
+    }
+}
+
+fn parse_request_arg(ctx: &Context, interface: &Interface, arg: &Arg) -> TokenStream {
+    let arg_name = ident!("{}", arg.name; Some(Case::SnakeCase));
+    if is_enum_arg(arg) {
+        let enum_typ = arg_type(ctx, interface, arg);
+        return quote! {
+            let #arg_name = #enum_typ::from(reader.uint()?);
+        };
+    }
+    if is_untyped_new_id(arg) {
+        return quote! {
+            let #arg_name = NewResource {
+                interface: reader.string()?.ok_or(WireError::UnexpectedNull)?,
+                version: reader.uint()?,
+                id: reader.new_id()?,
+            };
+        };
+    }
+    if let Some(resource_interface) = proxy_interface(arg) {
+        let resource_typ = ident!("{}", resource_interface; Some(Case::CamelCase));
+        let resource_typ = ctx.type_path(resource_interface, resource_typ);
+        if is_typed_new_id(arg) {
+            return quote! {
+                let #arg_name = self.connection.create_resource::<#resource_typ>(reader.new_id()?, self.version);
+            };
+        }
+        // Unlike a client, the server has to know every object a request refers to.
+        let resource = quote! {
+            self.connection
+                .get_resource(object_id)
+                .and_then(|resource| #resource_typ::from_enum(&resource))
+                .ok_or(WireError::UnknownObject(object_id))?
+        };
+        if is_nullable(arg) {
+            return quote! {
+                let #arg_name = match reader.object()? {
+                    Some(object_id) => Some(#resource),
+                    None => None,
+                };
+            };
+        }
+        return quote! {
+            let object_id = reader.object()?.ok_or(WireError::UnexpectedNull)?;
+            let #arg_name = #resource;
+        };
+    }
+    read_value(arg)
+}
+
+fn generate_code_for_resource(ctx: &Context, interface: &Interface) -> TokenStream {
+    let struct_name = ident!("{}", interface.name; Some(Case::CamelCase));
+
+    let interface_name = &interface.name;
+    let send_ev_functions = interface.events().enumerate().map(|(op_code, ev)| {
+        let op_code = op_code as u16;
+        let args = ev.args().filter(|arg| !is_typed_new_id(arg)).map(|arg| {
+            if is_untyped_new_id(arg) {
+                return quote! {version: Uint};
+            }
+            let arg_name = ident!("{}", arg.name; None);
+            let arg_typ = param_type(ctx, interface, arg);
+            quote! {#arg_name: #arg_typ}
+        });
+        let new_id = ev.args().find(|arg| arg.typ == "new_id");
+        let (generics, new_resource, ret_typ, ret_value) = match new_id {
+            Some(arg) => {
+                let arg_name = ident!("{}", arg.name; None);
+                let (generics, arg_typ, version) = if is_untyped_new_id(arg) {
+                    (quote! {<T: Interface>}, quote! {T}, quote! {version})
+                } else {
+                    (
+                        quote! {},
+                        arg_type(ctx, interface, arg),
+                        quote! {self.version},
+                    )
+                };
+                (
+                    generics,
+                    quote! {let #arg_name = self.connection.new_resource::<#arg_typ>(#version);},
+                    quote! {-> #arg_typ},
+                    quote! {#arg_name},
+                )
+            }
+            None => (quote! {}, quote! {}, quote! {}, quote! {}),
+        };
+        let (receiver, destroy) = if ev.is_destructor() {
+            (
+                quote! {self},
+                quote! {self.connection.destroy_resource(self.object_id);},
+            )
+        } else {
+            (quote! {&self}, quote! {})
+        };
+        let function_name = ident!("{}", &ev.name; None);
+        let doc = message_doc(ev.description(), ev.args(), ev.since);
+        // Events such as wl_output.geometry carry more arguments than clippy likes.
+        let allow_arguments = if ev.args().count() > 6 {
+            quote! {#[allow(clippy::too_many_arguments)]}
+        } else {
+            quote! {}
+        };
+        let send_args = ev.args().map(send_arg);
+        let writer = if ev.args().next().is_some() {
+            quote! {let mut writer = MessageWriter::new(self.object_id, #op_code);}
+        } else {
+            quote! {let writer = MessageWriter::new(self.object_id, #op_code);}
+        };
+
+        quote! {
+            #doc
+            #allow_arguments
+            pub fn #function_name #generics(#receiver, #(#args),*) #ret_typ {
+                #new_resource
+                #writer
+                #(#send_args)*
+                let (send_buffer, send_fd) = writer.finish();
+                self.connection.socket().send(&send_buffer, &send_fd);
+                #destroy
+                #ret_value
+            }
+        }
+    });
+
+    let parse_req = interface.requests().enumerate().map(|(op_code, req)| {
+        let op_code = op_code as u16;
+
+        let req_name_str = format!(
+            "{}{}Request",
+            interface.name.to_camel_case(),
+            req.name.to_camel_case()
+        );
+        let req_interface_name = ident!("{}Request", interface.name; Some(Case::CamelCase));
+        let req_name = request_struct_name(interface, req);
+
+        let parse_args = req.args().map(|arg| parse_request_arg(ctx, interface, arg));
+        let reader = if req.args().any(|arg| arg.typ == "fd") {
+            quote! {
+                let mut fds = self.connection.socket().received_fds();
+                let mut reader = MessageReader::with_fds(msg_body, &mut fds);
+            }
+        } else if req.args().next().is_some() {
+            quote! {let mut reader = MessageReader::new(msg_body);}
+        } else {
+            quote! {}
+        };
+        let arg_names = req
+            .args()
+            .map(|arg| ident!("{}", arg.name; Some(Case::SnakeCase)));
+        let request = quote! {
+            #req_interface_name::#req_name(#req_name {
+                sender_id,
+                #(#arg_names),*
+            })
+        };
+        let request = if ctx.core {
+            quote! {Request::#req_interface_name(#request)}
+        } else {
+            quote! {Request::Extension(Box::new(#request))}
+        };
+        quote! {
+            #op_code => {
+                info!("Receive request {}", #req_name_str);
+
+                #reader
+                #(#parse_args)*
+                Ok(#request)
+            }
+        }
+    });
+    let parse_request = if interface.requests().next().is_some() {
+        quote! {
+            match op_code {
+                #(#parse_req)*
+                _ => Err(WireError::UnknownOpcode(op_code)),
+            }
+        }
+    } else {
+        quote! {Err(WireError::UnknownOpcode(op_code))}
+    };
+    let (to_enum, from_enum, impl_extension) = if ctx.core {
+        (
+            quote! {WlResource::#struct_name(self)},
+            quote! {
+                match resource {
+                    WlResource::#struct_name(item) => Some(item.clone()),
+                    _ => None,
+                }
+            },
+            quote! {},
+        )
+    } else {
+        (
+            quote! {WlResource::Extension(Arc::new(self))},
+            quote! {
+                match resource {
+                    WlResource::Extension(item) => item.as_any().downcast_ref::<#struct_name>().cloned(),
+                    _ => None,
+                }
+            },
+            quote! {
+                impl ExtensionResource for #struct_name {
+                    fn parse_request(&self, sender_id: u32, op_code: u16, msg_body: &[u8]) -> Result<Request, WireError> {
+                        #struct_name::parse_request(self, sender_id, op_code, msg_body)
+                    }
+                    fn as_any(&self) -> &dyn Any {
+                        self
+                    }
+                }
+            },
+        )
+    };
+    let struct_doc = doc_attrs(&description_lines(interface.description()));
+    let interface_version = interface.version;
+    let descriptor_name = descriptor_name(&interface.name);
+    let enums = interface
+        .enums()
+        .map(|enumeration| generate_code_for_enum(interface, enumeration));
+    quote! {
+        #struct_doc
+        #[derive(Clone)]
+        pub struct #struct_name {
+            #[allow(dead_code)]
+            pub object_id: u32,
+            version: u32,
+            #[allow(dead_code)]
+            pub connection: Connection,
+        }
+        impl WlRawResource for #struct_name {
+            fn new(object_id: u32, version: u32, connection: Connection) -> #struct_name {
+                #struct_name { object_id, version, connection }
+            }
+            fn object_id(&self) -> u32 {
+                self.object_id
+            }
+            fn to_enum(self) -> WlResource {
+                #to_enum
+            }
+            fn from_enum(resource: &WlResource) -> Option<#struct_name> {
+                #from_enum
+            }
+        }
+        #impl_extension
+        impl Interface for #struct_name {
+            const NAME: &'static str = #interface_name;
+            const VERSION: u32 = #interface_version;
+            const DESCRIPTOR: &'static InterfaceDescriptor = &#descriptor_name;
+        }
+        impl #struct_name {
+            pub fn version(&self) -> u32 {
+                self.version
+            }
+            #[allow(unused_variables)]
+            fn parse_request(&self, sender_id: u32, op_code: u16, msg_body: &[u8]) -> Result<Request, WireError> {
+                #parse_request
+            }
+            #(#send_ev_functions)*
+        }
+        #(#enums)*
+    }
+}
+
+fn generate_code_for_requests(ctx: &Context) -> TokenStream {
+    let request_enums = ctx.protocol.interfaces().map(|interface| {
+        let request_enum_name = ident!("{}Request", interface.name; Some(Case::CamelCase));
+        let request_enum_doc = format!(" Requests sent to `{}` resources.", interface.name);
+        let request_structs = interface.requests().map(|req| {
+            let req_struct_name = request_struct_name(interface, req);
+            let doc = description_doc(req.description(), req.since);
+            let request_field_docs = req.args().map(arg_doc);
+            let request_fields = req.args().map(|arg| {
+                let arg_name = ident!("{}", arg.name; Some(Case::SnakeCase));
+                let arg_typ = field_type(ctx, interface, arg);
+                quote! {#arg_name: #arg_typ}
+            });
+            quote! {
+                #doc
+                pub struct #req_struct_name {
+                    /// Id of the resource the request was sent to.
+                    #[allow(dead_code)]
+                    pub sender_id: u32,
+                    #(#request_field_docs #[allow(dead_code)]pub #request_fields),*
+                }
+            }
+        });
+        let request_struct_names = interface.requests().map(|req| {
+            let req_struct_name = request_struct_name(interface, req);
+            let doc = doc_attrs(&crate::doc_lines(
+                req.description()
+                    .map_or("", |description| &description.summary),
+                "",
+            ));
+            quote! {
+                #doc
+                #req_struct_name(#req_struct_name)
+            }
+        });
+        quote! {
+            #(#request_structs)*
+            #[doc = #request_enum_doc]
+            pub enum #request_enum_name {
+                #(#request_struct_names),*
+            }
+        }
+    });
+    if !ctx.core {
+        return quote! {
+            #(#request_enums)*
+        };
+    }
+
+    let enum_interface_names = ctx.protocol.interfaces().map(|interface| {
+        let interface_name = ident!("{}", interface.name; Some(Case::CamelCase));
+        quote! {#interface_name(#interface_name)}
+    });
+    let enum_request_names = ctx.protocol.interfaces().map(|interface| {
+        let request_name = ident!("{}Request", interface.name; Some(Case::CamelCase));
+        quote! {#request_name(#request_name)}
+    });
+    let parse_request_for_interface = ctx.protocol.interfaces().map(|interface| {
+        let interface_name = ident!("{}", interface.name; Some(Case::CamelCase));
+        quote! {
+            WlResource::#interface_name(resource) => resource.parse_request(sender_id, op_code, msg_body)
+        }
+    });
+    let impl_wl_get_resource = ctx.protocol.interfaces().map(|interface| {
+        let interface_name = ident!("{}", interface.name; Some(Case::CamelCase));
+        let get_function_name = ident!("try_get_{}", interface.name; Some(Case::SnakeCase));
+        quote! {
+            #[allow(dead_code)]
+            pub fn #get_function_name(&self) -> Option<#interface_name> {
+                match self {
+                    WlResource::#interface_name(item) => Some(item.clone()),
+                    _ => None,
+                }
+            }
+        }
+    });
+    quote! {
+        pub enum WlResource {
+            #(#enum_interface_names,)*
+            /// A resource of a protocol generated outside of saiko.
+            Extension(Arc<dyn ExtensionResource>),
+        }
+        pub enum Request {
+            #(#enum_request_names,)*
+            /// A request of a protocol generated outside of saiko, to be downcast
+            /// to the request enum of its interface.
+            Extension(Box<dyn Any + Send + Sync>),
+        }
+        #(#request_enums)*
+        impl WlResource {
+            pub fn parse_request(&self, sender_id: u32, op_code: u16, msg_body: &[u8]) -> Result<Request, WireError> {
+                match self {
+                    #(#parse_request_for_interface,)*
+                    WlResource::Extension(resource) => resource.parse_request(sender_id, op_code, msg_body),
+                }
+            }
+            #(#impl_wl_get_resource)*
+        }
+    }
+}
+
+fn generate_code_for_protocol(ctx: &Context) -> TokenStream {
+    let codes_for_every_interface = ctx
+        .protocol
+        .interfaces()
+        .map(|interface| generate_code_for_resource(ctx, interface));
+    let code_for_requests = generate_code_for_requests(ctx);
+    let descriptors = generate_descriptors(ctx);
+    quote! {
+        #(#codes_for_every_interface)*
+        #code_for_requests
+        #descriptors
+    }
+}
+
+/// Generates the contents of a module with resources for the server side of a
+/// protocol that builds on the core wayland protocol, for use with
+/// `saiko::server::Connection`.
+pub fn generate_server_protocol_code(protocol: &Protocol) -> Result<String, GenerateError> {
+    generate_server_protocol_code_with_dependencies(protocol, &[])
+}
+
+/// Generates the server module of a protocol like
+/// `generate_server_protocol_code`, for protocols that also build on other
+/// extension protocols.
+pub fn generate_server_protocol_code_with_dependencies(
+    protocol: &Protocol,
+    dependencies: &[Dependency],
+) -> Result<String, GenerateError> {
+    let ctx = Context::extension(protocol, dependencies, true)?;
+    let code_for_protocol = generate_code_for_protocol(&ctx);
+    let code = quote! {
+        #[allow(unused_imports)]
+        use ::saiko::descriptor::{InterfaceDescriptor, MessageDescriptor};
+        #[allow(unused_imports)]
+        use ::saiko::error::WireError;
+        #[allow(unused_imports)]
+        use ::saiko::log::info;
+        #[allow(unused_imports)]
+        use ::saiko::server::{Connection, NewResource};
+        #[allow(unused_imports)]
+        use ::saiko::types::Fixed;
+        #[allow(unused_imports)]
+        use ::saiko::wayland_server::{ExtensionResource, Interface, Request, WlRawResource, WlResource};
+        #[allow(unused_imports)]
+        use ::saiko::wire::{MessageReader, MessageWriter};
+        #[allow(unused_imports)]
+        use std::any::Any;
+        #[allow(unused_imports)]
+        use std::os::unix::io::{OwnedFd, RawFd};
+        #[allow(unused_imports)]
+        use std::sync::Arc;
+
+        #[allow(dead_code)]
+        type Uint=u32;
+        #[allow(dead_code)]
+        type Int=i32;
+        #[allow(dead_code)]
+        type Fd=RawFd;
+        #[allow(dead_code)]
+        type Object=u32;
+        #[allow(dead_code)]
+        type Array=Vec<u8>;
+
+        #code_for_protocol
+    };
+
+    Ok(code.to_string())
+}
+
+/// Reads a protocol description and generates its server module like
+/// `generate_server_protocol_code`.
+pub fn generate_server_protocol_code_from_file<P: AsRef<Path>>(
+    path: P,
+) -> Result<String, GenerateError> {
+    let protocol = wayland_protocol_scanner::parse_protocol_from_file(path)?;
+    generate_server_protocol_code(&protocol)
+}
+
+pub fn generate_wayland_server_code() -> String {
+    let ctx = Context::core(true);
+    let code_for_protocol = generate_code_for_protocol(&ctx);
+
+    let code = quote! {
+        use crate::descriptor::{InterfaceDescriptor, MessageDescriptor};
+        use crate::error::WireError;
+        use crate::server::{Connection, NewResource};
+        use crate::types::Fixed;
+        use crate::wire::{MessageReader, MessageWriter};
+        use std::any::Any;
+        use std::os::unix::io::{OwnedFd, RawFd};
+        use std::sync::Arc;
+
+        type Uint=u32;
+        type Int=i32;
+        type Fd=RawFd;
+        type Object=u32;
+
+
+        #code_for_protocol
+
+        pub trait WlRawResource: Sized {
+            fn new(object_id: u32, version: u32, connection: Connection) -> Self;
+            fn object_id(&self) -> u32;
+            fn to_enum(self) -> WlResource;
+            fn from_enum(resource: &WlResource) -> Option<Self>;
+        }
+        pub trait Interface: WlRawResource + Clone {
+            const NAME: &'static str;
+            const VERSION: u32;
+            const DESCRIPTOR: &'static InterfaceDescriptor;
+        }
+        /// Resources generated for other protocols, stored as `WlResource::Extension`.
+        pub trait ExtensionResource: Send + Sync {
+            fn parse_request(&self, sender_id: u32, op_code: u16, msg_body: &[u8]) -> Result<Request, WireError>;
+            fn as_any(&self) -> &dyn Any;
+        }
+    };
+
+    code.to_string()
+}
@@ -0,0 +1,105 @@
+extern crate heck;
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate wayland_protocol_code_generator;
+extern crate wayland_protocol_scanner;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+use heck::SnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use std::env;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Path};
+use wayland_protocol_code_generator::Dependency;
+use wayland_protocol_scanner::Protocol;
+
+struct ProtocolInput {
+    path: LitStr,
+    dependencies: Vec<(LitStr, Path)>,
+}
+
+impl Parse for ProtocolInput {
+    fn parse(input: ParseStream) -> syn::Result<ProtocolInput> {
+        let path = input.parse()?;
+        let mut dependencies = Vec::new();
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let dependency = input.parse()?;
+            input.parse::<Token![=>]>()?;
+            dependencies.push((dependency, input.parse()?));
+        }
+        Ok(ProtocolInput { path, dependencies })
+    }
+}
+
+/// Generates a module named after the protocol with proxies for its
+/// interfaces, e.g. `saiko::protocol!("protocols/viewporter.xml");` creates
+/// `pub mod viewporter`.
+///
+/// Protocols that build on other extension protocols list them along with the
+/// module their proxies were generated into:
+///
+/// ```ignore
+/// saiko::protocol!("protocols/xdg-shell.xml");
+/// saiko::protocol!(
+///     "protocols/xdg-decoration-unstable-v1.xml",
+///     "protocols/xdg-shell.xml" => crate::xdg_shell,
+/// );
+/// ```
+///
+/// The paths are relative to the directory of the crate's `Cargo.toml`.
+#[proc_macro]
+pub fn protocol(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as ProtocolInput);
+    match expand(&input) {
+        Ok(code) => code.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn load(path: &LitStr) -> Result<(Protocol, String), syn::Error> {
+    let manifest_dir =
+        env::var("CARGO_MANIFEST_DIR").map_err(|err| syn::Error::new(path.span(), err))?;
+    let file = PathBuf::from(manifest_dir).join(path.value());
+    let protocol = wayland_protocol_scanner::parse_protocol_from_file(&file)
+        .map_err(|err| syn::Error::new(path.span(), err))?;
+    Ok((protocol, file.to_string_lossy().into_owned()))
+}
+
+fn expand(input: &ProtocolInput) -> Result<TokenStream, syn::Error> {
+    let (protocol, file) = load(&input.path)?;
+    let mut files = vec![file];
+    let mut dependencies = Vec::new();
+    for (path, module) in &input.dependencies {
+        let (dependency, file) = load(path)?;
+        files.push(file);
+        dependencies.push((dependency, quote!(#module).to_string()));
+    }
+    let dependencies: Vec<Dependency> = dependencies
+        .iter()
+        .map(|(protocol, module)| Dependency { protocol, module })
+        .collect();
+
+    let span = input.path.span();
+    let code: TokenStream =
+        wayland_protocol_code_generator::generate_protocol_code_with_dependencies(
+            &protocol,
+            &dependencies,
+        )
+        .map_err(|err| syn::Error::new(span, err))?
+        .parse()
+        .map_err(|_| syn::Error::new(span, "generated code is not valid Rust"))?;
+
+    let module_name = Ident::new(&protocol.name.to_snake_case(), Span::call_site());
+    // Makes the compiler rebuild the module when one of the protocols changes.
+    Ok(quote! {
+        pub mod #module_name {
+            #(const _: &[u8] = include_bytes!(#files);)*
+
+            #code
+        }
+    })
+}
@@ -0,0 +1,30 @@
+extern crate wayland_protocol_scanner;
+
+use std::env;
+use std::process;
+use wayland_protocol_scanner::parse_protocol_from_file;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} <old.xml> <new.xml>", args[0]);
+        process::exit(2);
+    }
+
+    let load = |path: &str| {
+        parse_protocol_from_file(path).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(2);
+        })
+    };
+    let old = load(&args[1]);
+    let new = load(&args[2]);
+
+    let changes = old.diff(&new);
+    for change in &changes {
+        println!("{}", change);
+    }
+    if changes.iter().any(|change| change.breaking) {
+        process::exit(1);
+    }
+}
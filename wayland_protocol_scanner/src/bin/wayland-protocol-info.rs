@@ -0,0 +1,96 @@
+extern crate wayland_protocol_scanner;
+
+use std::env;
+use std::process;
+use wayland_protocol_scanner::{
+    parse_protocol_from_file, parse_wayland_protocol, Arg, Interface, Protocol,
+};
+
+// One code per value on the wire, so a new_id without an interface is `s u n`.
+fn wire_signature<'a, I: Iterator<Item = &'a Arg>>(args: I) -> String {
+    let mut codes = Vec::new();
+    let mut code = String::new();
+    for c in args.map(Arg::signature).collect::<String>().chars() {
+        code.push(c);
+        if c != '?' {
+            codes.push(code.clone());
+            code.clear();
+        }
+    }
+    codes.join(" ")
+}
+
+fn since(since: Option<u32>) -> String {
+    match since {
+        Some(since) => format!(" since {}", since),
+        None => String::new(),
+    }
+}
+
+fn print_interface(interface: &Interface) {
+    println!("interface {} version {}", interface.name, interface.version);
+    for (opcode, req) in interface.requests().enumerate() {
+        let destructor = if req.is_destructor() {
+            " destructor"
+        } else {
+            ""
+        };
+        println!(
+            "  request {} {}({}){}{}",
+            opcode,
+            req.name,
+            wire_signature(req.args()),
+            since(req.since),
+            destructor
+        );
+    }
+    for (opcode, ev) in interface.events().enumerate() {
+        println!(
+            "  event {} {}({}){}",
+            opcode,
+            ev.name,
+            wire_signature(ev.args()),
+            since(ev.since)
+        );
+    }
+    for enumeration in interface.enums() {
+        let bitfield = if enumeration.bitfield {
+            " bitfield"
+        } else {
+            ""
+        };
+        println!(
+            "  enum {}{}{}",
+            enumeration.name,
+            since(enumeration.since),
+            bitfield
+        );
+        for entry in enumeration.entries() {
+            println!("    {} = {}{}", entry.name, entry.value, since(entry.since));
+        }
+    }
+}
+
+fn print_protocol(protocol: &Protocol) {
+    println!("protocol {}", protocol.name);
+    for interface in protocol.interfaces() {
+        print_interface(interface);
+    }
+}
+
+fn main() {
+    let paths: Vec<String> = env::args().skip(1).collect();
+    let result = if paths.is_empty() {
+        parse_wayland_protocol().map(|protocol| vec![protocol])
+    } else {
+        paths.iter().map(parse_protocol_from_file).collect()
+    };
+
+    match result {
+        Ok(protocols) => protocols.iter().for_each(print_protocol),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
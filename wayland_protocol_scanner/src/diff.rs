@@ -0,0 +1,484 @@
+use super::{Arg, Enum, Interface, Protocol};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind {
+    Request,
+    Event,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    InterfaceAdded,
+    InterfaceRemoved,
+    VersionChanged {
+        old: u32,
+        new: u32,
+    },
+    MessageAdded {
+        kind: MessageKind,
+        opcode: usize,
+    },
+    MessageRemoved {
+        kind: MessageKind,
+        opcode: usize,
+    },
+    OpcodeChanged {
+        kind: MessageKind,
+        old: usize,
+        new: usize,
+    },
+    SinceChanged {
+        kind: MessageKind,
+        old: u32,
+        new: u32,
+    },
+    ArgsChanged {
+        kind: MessageKind,
+        old: String,
+        new: String,
+    },
+    EnumAdded,
+    EnumRemoved,
+    BitfieldChanged {
+        old: bool,
+        new: bool,
+    },
+    EntryAdded {
+        value: String,
+    },
+    EntryRemoved {
+        value: String,
+    },
+    EntryValueChanged {
+        old: String,
+        new: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub location: String,
+    pub kind: ChangeKind,
+    pub breaking: bool,
+}
+
+impl fmt::Display for MessageKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MessageKind::Request => write!(f, "request"),
+            MessageKind::Event => write!(f, "event"),
+        }
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChangeKind::InterfaceAdded => write!(f, "interface added"),
+            ChangeKind::InterfaceRemoved => write!(f, "interface removed"),
+            ChangeKind::VersionChanged { old, new } => {
+                write!(f, "version changed from {} to {}", old, new)
+            }
+            ChangeKind::MessageAdded { kind, opcode } => {
+                write!(f, "{} added with opcode {}", kind, opcode)
+            }
+            ChangeKind::MessageRemoved { kind, opcode } => {
+                write!(f, "{} with opcode {} removed", kind, opcode)
+            }
+            ChangeKind::OpcodeChanged { kind, old, new } => {
+                write!(f, "{} opcode changed from {} to {}", kind, old, new)
+            }
+            ChangeKind::SinceChanged { kind, old, new } => {
+                write!(f, "{} since changed from {} to {}", kind, old, new)
+            }
+            ChangeKind::ArgsChanged { kind, old, new } => {
+                write!(f, "{} arguments changed from ({}) to ({})", kind, old, new)
+            }
+            ChangeKind::EnumAdded => write!(f, "enum added"),
+            ChangeKind::EnumRemoved => write!(f, "enum removed"),
+            ChangeKind::BitfieldChanged { old, new } => {
+                write!(f, "bitfield changed from {} to {}", old, new)
+            }
+            ChangeKind::EntryAdded { value } => write!(f, "entry added with value {}", value),
+            ChangeKind::EntryRemoved { value } => {
+                write!(f, "entry with value {} removed", value)
+            }
+            ChangeKind::EntryValueChanged { old, new } => {
+                write!(f, "entry value changed from {} to {}", old, new)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.kind)?;
+        if self.breaking {
+            write!(f, " (breaking)")?;
+        }
+        Ok(())
+    }
+}
+
+impl Protocol {
+    pub fn diff(&self, newer: &Protocol) -> Vec<Change> {
+        diff_protocols(self, newer)
+    }
+}
+
+struct Message<'a> {
+    name: &'a str,
+    since: u32,
+    args: Vec<&'a Arg>,
+}
+
+fn requests(interface: &Interface) -> Vec<Message<'_>> {
+    interface
+        .requests()
+        .map(|req| Message {
+            name: &req.name,
+            since: req.since.unwrap_or(1),
+            args: req.args().collect(),
+        })
+        .collect()
+}
+
+fn events(interface: &Interface) -> Vec<Message<'_>> {
+    interface
+        .events()
+        .map(|ev| Message {
+            name: &ev.name,
+            since: ev.since.unwrap_or(1),
+            args: ev.args().collect(),
+        })
+        .collect()
+}
+
+// Everything that affects the wire format or the generated bindings, e.g.
+// `?object<wl_surface> surface` or `uint{wl_shm.format} format`.
+fn describe_arg(arg: &Arg) -> String {
+    let mut description = String::new();
+    if arg.allow_null {
+        description.push('?');
+    }
+    description.push_str(&arg.typ);
+    if let Some(interface) = &arg.interface {
+        description.push_str(&format!("<{}>", interface));
+    }
+    if let Some(enum_name) = &arg.enum_name {
+        description.push_str(&format!("{{{}}}", enum_name));
+    }
+    format!("{} {}", description, arg.name)
+}
+
+fn describe_args(args: &[&Arg]) -> String {
+    let args: Vec<String> = args.iter().map(|arg| describe_arg(arg)).collect();
+    args.join(", ")
+}
+
+fn same_wire_format(old: &[&Arg], new: &[&Arg]) -> bool {
+    old.len() == new.len()
+        && old.iter().zip(new).all(|(old, new)| {
+            old.typ == new.typ && old.interface == new.interface && old.allow_null == new.allow_null
+        })
+}
+
+struct Differ {
+    changes: Vec<Change>,
+}
+
+impl Differ {
+    fn report(&mut self, location: String, kind: ChangeKind, breaking: bool) {
+        self.changes.push(Change {
+            location,
+            kind,
+            breaking,
+        });
+    }
+
+    fn diff_messages(
+        &mut self,
+        kind: MessageKind,
+        old: &Interface,
+        old_messages: &[Message],
+        new: &Interface,
+        new_messages: &[Message],
+    ) {
+        let new_index: HashMap<&str, usize> = new_messages
+            .iter()
+            .enumerate()
+            .map(|(opcode, msg)| (msg.name, opcode))
+            .collect();
+        for (opcode, msg) in old_messages.iter().enumerate() {
+            let location = format!("{}.{}", old.name, msg.name);
+            let new_opcode = match new_index.get(msg.name) {
+                Some(&new_opcode) => new_opcode,
+                None => {
+                    self.report(location, ChangeKind::MessageRemoved { kind, opcode }, true);
+                    continue;
+                }
+            };
+            let new_msg = &new_messages[new_opcode];
+            if new_opcode != opcode {
+                let change = ChangeKind::OpcodeChanged {
+                    kind,
+                    old: opcode,
+                    new: new_opcode,
+                };
+                self.report(location.clone(), change, true);
+            }
+            if new_msg.since != msg.since {
+                let change = ChangeKind::SinceChanged {
+                    kind,
+                    old: msg.since,
+                    new: new_msg.since,
+                };
+                self.report(location.clone(), change, true);
+            }
+            let (old_args, new_args) = (describe_args(&msg.args), describe_args(&new_msg.args));
+            if old_args != new_args {
+                let breaking = !same_wire_format(&msg.args, &new_msg.args);
+                let change = ChangeKind::ArgsChanged {
+                    kind,
+                    old: old_args,
+                    new: new_args,
+                };
+                self.report(location, change, breaking);
+            }
+        }
+
+        let old_names: Vec<&str> = old_messages.iter().map(|msg| msg.name).collect();
+        for (opcode, msg) in new_messages.iter().enumerate() {
+            if !old_names.contains(&msg.name) {
+                // A new message is only compatible when it is appended after the old ones
+                // and announced with a version the old interface did not have.
+                let breaking = opcode < old_messages.len() || msg.since <= old.version;
+                let location = format!("{}.{}", new.name, msg.name);
+                self.report(
+                    location,
+                    ChangeKind::MessageAdded { kind, opcode },
+                    breaking,
+                );
+            }
+        }
+    }
+
+    fn diff_enum(&mut self, interface: &Interface, old: &Enum, new: &Enum) {
+        let scope = format!("{}.{}", interface.name, old.name);
+        // Values of a bitfield are combined, which receivers of a plain enum do
+        // not expect, and the other way round.
+        if old.bitfield != new.bitfield {
+            let change = ChangeKind::BitfieldChanged {
+                old: old.bitfield,
+                new: new.bitfield,
+            };
+            self.report(scope.clone(), change, true);
+        }
+        for entry in old.entries() {
+            let location = format!("{}.{}", scope, entry.name);
+            match new.entries().find(|new_entry| new_entry.name == entry.name) {
+                Some(new_entry) => {
+                    if new_entry.parse_value() != entry.parse_value() {
+                        let change = ChangeKind::EntryValueChanged {
+                            old: entry.value.clone(),
+                            new: new_entry.value.clone(),
+                        };
+                        self.report(location, change, true);
+                    }
+                }
+                None => {
+                    let change = ChangeKind::EntryRemoved {
+                        value: entry.value.clone(),
+                    };
+                    self.report(location, change, true);
+                }
+            }
+        }
+        for entry in new.entries() {
+            if old.entries().all(|old_entry| old_entry.name != entry.name) {
+                let location = format!("{}.{}", scope, entry.name);
+                let change = ChangeKind::EntryAdded {
+                    value: entry.value.clone(),
+                };
+                self.report(location, change, false);
+            }
+        }
+    }
+
+    fn diff_interface(&mut self, old: &Interface, new: &Interface) {
+        if old.version != new.version {
+            let change = ChangeKind::VersionChanged {
+                old: old.version,
+                new: new.version,
+            };
+            self.report(old.name.clone(), change, new.version < old.version);
+        }
+
+        let (old_requests, new_requests) = (requests(old), requests(new));
+        self.diff_messages(MessageKind::Request, old, &old_requests, new, &new_requests);
+        let (old_events, new_events) = (events(old), events(new));
+        self.diff_messages(MessageKind::Event, old, &old_events, new, &new_events);
+
+        for enumeration in old.enums() {
+            match new
+                .enums()
+                .find(|new_enum| new_enum.name == enumeration.name)
+            {
+                Some(new_enum) => self.diff_enum(old, enumeration, new_enum),
+                None => {
+                    let location = format!("{}.{}", old.name, enumeration.name);
+                    self.report(location, ChangeKind::EnumRemoved, true);
+                }
+            }
+        }
+        for enumeration in new.enums() {
+            if old
+                .enums()
+                .all(|old_enum| old_enum.name != enumeration.name)
+            {
+                let location = format!("{}.{}", new.name, enumeration.name);
+                self.report(location, ChangeKind::EnumAdded, false);
+            }
+        }
+    }
+}
+
+pub fn diff_protocols(old: &Protocol, new: &Protocol) -> Vec<Change> {
+    let mut differ = Differ {
+        changes: Vec::new(),
+    };
+
+    for interface in old.interfaces() {
+        match new
+            .interfaces()
+            .find(|new_if| new_if.name == interface.name)
+        {
+            Some(new_interface) => differ.diff_interface(interface, new_interface),
+            None => differ.report(interface.name.clone(), ChangeKind::InterfaceRemoved, true),
+        }
+    }
+    for interface in new.interfaces() {
+        if old.interfaces().all(|old_if| old_if.name != interface.name) {
+            differ.report(interface.name.clone(), ChangeKind::InterfaceAdded, false);
+        }
+    }
+
+    differ.changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_protocol_from_str;
+
+    fn protocol(interface: &str) -> Protocol {
+        parse_protocol_from_str(&format!(
+            r#"<protocol name="test">{}</protocol>"#,
+            interface
+        ))
+        .unwrap()
+    }
+
+    fn kinds(old: &str, new: &str) -> Vec<(ChangeKind, bool)> {
+        diff_protocols(&protocol(old), &protocol(new))
+            .into_iter()
+            .map(|change| (change.kind, change.breaking))
+            .collect()
+    }
+
+    #[test]
+    fn identical_protocols_have_no_changes() {
+        let interface = r#"<interface name="a" version="1">
+            <request name="r"><arg name="o" type="object" interface="a" allow-null="true"/></request>
+        </interface>"#;
+        assert_eq!(kinds(interface, interface), vec![]);
+    }
+
+    #[test]
+    fn allow_null_removed_is_breaking() {
+        let old = r#"<interface name="a" version="1">
+            <request name="r"><arg name="o" type="object" interface="a" allow-null="true"/></request>
+        </interface>"#;
+        let new = r#"<interface name="a" version="1">
+            <request name="r"><arg name="o" type="object" interface="a"/></request>
+        </interface>"#;
+        assert_eq!(
+            kinds(old, new),
+            vec![(
+                ChangeKind::ArgsChanged {
+                    kind: MessageKind::Request,
+                    old: "?object<a> o".to_string(),
+                    new: "object<a> o".to_string(),
+                },
+                true
+            )]
+        );
+    }
+
+    #[test]
+    fn enum_attribute_change_keeps_the_wire_format() {
+        let old = r#"<interface name="a" version="1">
+            <event name="e"><arg name="v" type="uint"/></event>
+        </interface>"#;
+        let new = r#"<interface name="a" version="1">
+            <event name="e"><arg name="v" type="uint" enum="b"/></event>
+        </interface>"#;
+        assert_eq!(
+            kinds(old, new),
+            vec![(
+                ChangeKind::ArgsChanged {
+                    kind: MessageKind::Event,
+                    old: "uint v".to_string(),
+                    new: "uint{b} v".to_string(),
+                },
+                false
+            )]
+        );
+    }
+
+    #[test]
+    fn bitfield_change_is_breaking() {
+        let old = r#"<interface name="a" version="1">
+            <enum name="b"><entry name="x" value="1"/></enum>
+        </interface>"#;
+        let new = r#"<interface name="a" version="1">
+            <enum name="b" bitfield="true"><entry name="x" value="1"/></enum>
+        </interface>"#;
+        assert_eq!(
+            kinds(old, new),
+            vec![(
+                ChangeKind::BitfieldChanged {
+                    old: false,
+                    new: true
+                },
+                true
+            )]
+        );
+    }
+
+    #[test]
+    fn appended_message_with_new_version_is_compatible() {
+        let old = r#"<interface name="a" version="1">
+            <request name="r"/>
+        </interface>"#;
+        let new = r#"<interface name="a" version="2">
+            <request name="r"/>
+            <request name="s" since="2"/>
+        </interface>"#;
+        assert_eq!(
+            kinds(old, new),
+            vec![
+                (ChangeKind::VersionChanged { old: 1, new: 2 }, false),
+                (
+                    ChangeKind::MessageAdded {
+                        kind: MessageKind::Request,
+                        opcode: 1
+                    },
+                    false
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use xml::common::Position as XmlPosition;
+use xml::reader::{EventReader, XmlEvent};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: u64,
+    pub column: u64,
+}
+
+#[derive(Debug)]
+pub enum ParseErrorKind {
+    Io(io::Error),
+    Syntax(String),
+    Invalid(String),
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub position: Option<Position>,
+    pub element: Option<String>,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::Io(err) => write!(f, "{}", err),
+            ParseErrorKind::Syntax(msg) => write!(f, "malformed XML: {}", msg),
+            ParseErrorKind::Invalid(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}", file.display())?,
+            None => write!(f, "<protocol>")?,
+        }
+        if let Some(position) = self.position {
+            write!(f, ":{}:{}", position.line, position.column)?;
+        }
+        write!(f, ": ")?;
+        if let Some(element) = &self.element {
+            write!(f, "<{}>: ", element)?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl ParseError {
+    pub(crate) fn io(err: io::Error, file: Option<PathBuf>) -> ParseError {
+        ParseError {
+            file,
+            position: None,
+            element: None,
+            kind: ParseErrorKind::Io(err),
+        }
+    }
+
+    pub(crate) fn from_xml(
+        err: serde_xml_rs::Error,
+        contents: &str,
+        file: Option<PathBuf>,
+    ) -> ParseError {
+        if let serde_xml_rs::ErrorKind::Syntax(err) = err.kind() {
+            return ParseError {
+                file,
+                position: Some(to_position(err.position())),
+                element: None,
+                kind: ParseErrorKind::Syntax(err.msg().to_string()),
+            };
+        }
+
+        match locate(contents) {
+            Some((position, element, msg)) => ParseError {
+                file,
+                position: Some(position),
+                element: Some(element),
+                kind: ParseErrorKind::Invalid(msg),
+            },
+            None => ParseError {
+                file,
+                position: None,
+                element: None,
+                kind: ParseErrorKind::Invalid(err.to_string()),
+            },
+        }
+    }
+}
+
+struct ElementSchema {
+    name: &'static str,
+    required: &'static [&'static str],
+    numbers: &'static [&'static str],
+    flags: &'static [&'static str],
+    children: Option<&'static [&'static str]>,
+}
+
+const SCHEMA: [ElementSchema; 9] = [
+    ElementSchema {
+        name: "protocol",
+        required: &["name"],
+        numbers: &[],
+        flags: &[],
+        children: Some(&["copyright", "description", "interface"]),
+    },
+    ElementSchema {
+        name: "interface",
+        required: &["name", "version"],
+        numbers: &["version"],
+        flags: &[],
+        children: Some(&["description", "request", "event", "enum"]),
+    },
+    ElementSchema {
+        name: "request",
+        required: &["name"],
+        numbers: &["since", "deprecated-since"],
+        flags: &[],
+        children: Some(&["description", "arg"]),
+    },
+    ElementSchema {
+        name: "event",
+        required: &["name"],
+        numbers: &["since", "deprecated-since"],
+        flags: &[],
+        children: Some(&["description", "arg"]),
+    },
+    ElementSchema {
+        name: "enum",
+        required: &["name"],
+        numbers: &["since"],
+        flags: &["bitfield"],
+        children: Some(&["description", "entry"]),
+    },
+    ElementSchema {
+        name: "entry",
+        required: &["name", "value"],
+        numbers: &["since", "deprecated-since"],
+        flags: &[],
+        children: None,
+    },
+    ElementSchema {
+        name: "arg",
+        required: &["name"],
+        numbers: &[],
+        flags: &["allow-null"],
+        children: None,
+    },
+    ElementSchema {
+        name: "description",
+        required: &[],
+        numbers: &[],
+        flags: &[],
+        children: Some(&[]),
+    },
+    ElementSchema {
+        name: "copyright",
+        required: &[],
+        numbers: &[],
+        flags: &[],
+        children: Some(&[]),
+    },
+];
+
+fn to_position(position: xml::common::TextPosition) -> Position {
+    Position {
+        line: position.row + 1,
+        column: position.column + 1,
+    }
+}
+
+// serde_xml_rs does not tell where deserialization stopped, so walk the document
+// again and report the first element that does not fit the protocol model.
+fn locate(contents: &str) -> Option<(Position, String, String)> {
+    let mut reader = EventReader::from_str(contents);
+    let mut stack: Vec<Option<&ElementSchema>> = Vec::new();
+
+    loop {
+        let event = reader.next().ok()?;
+        let position = to_position(reader.position());
+        match event {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => {
+                let element = name.local_name;
+                let allowed = match stack.last() {
+                    Some(Some(parent)) => parent
+                        .children
+                        .is_none_or(|children| children.contains(&&element[..])),
+                    Some(None) => true,
+                    None => element == "protocol",
+                };
+                if !allowed {
+                    let msg = format!("unexpected element <{}>", element);
+                    return Some((position, element, msg));
+                }
+
+                let schema = SCHEMA.iter().find(|schema| schema.name == element);
+                if let Some(schema) = schema {
+                    let value_of = |attr: &str| {
+                        attributes
+                            .iter()
+                            .find(|attribute| attribute.name.local_name == attr)
+                            .map(|attribute| &attribute.value[..])
+                    };
+                    for attr in schema.required {
+                        if value_of(attr).is_none() {
+                            let msg = format!("missing attribute `{}`", attr);
+                            return Some((position, element, msg));
+                        }
+                    }
+                    for attr in schema.numbers {
+                        if let Some(value) = value_of(attr) {
+                            if value.parse::<u32>().is_err() {
+                                let msg = format!("`{}` is not a valid `{}`", value, attr);
+                                return Some((position, element, msg));
+                            }
+                        }
+                    }
+                    for attr in schema.flags {
+                        if let Some(value) = value_of(attr) {
+                            if value.parse::<bool>().is_err() {
+                                let msg = format!("`{}` is not a valid `{}`", value, attr);
+                                return Some((position, element, msg));
+                            }
+                        }
+                    }
+                }
+                stack.push(schema);
+            }
+            XmlEvent::EndElement { .. } => {
+                stack.pop();
+            }
+            XmlEvent::EndDocument => return None,
+            _ => {}
+        }
+    }
+}
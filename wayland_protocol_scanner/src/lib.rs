@@ -1,100 +1,347 @@
 #[macro_use]
 extern crate serde_derive;
 extern crate serde;
+extern crate serde_json;
 extern crate serde_xml_rs;
+extern crate xml;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer};
 use serde_xml_rs::from_str;
 use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Deserialize)]
+mod diff;
+mod error;
+mod serialize;
+mod set;
+mod validate;
+
+pub use diff::{diff_protocols, Change, ChangeKind, MessageKind};
+pub use error::{ParseError, ParseErrorKind, Position};
+pub use set::ProtocolSet;
+pub use validate::{validate_protocols, Diagnostic, DiagnosticKind};
+
+fn deserialize_flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Description {
+    #[serde(default)]
+    pub summary: String,
+    #[serde(rename(serialize = "body", deserialize = "$value"), default)]
+    pub body: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Copyright {
+    #[serde(rename(serialize = "body", deserialize = "$value"), default)]
+    pub body: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Entry {
     pub name: String,
     pub value: String,
+    #[serde(default)]
     pub summary: String,
+    pub since: Option<u32>,
+    #[serde(rename = "deprecated-since")]
+    pub deprecated_since: Option<u32>,
+    pub description: Option<Description>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EnumChild {
-    Description(String),
+    Description(Description),
     Entry(Entry),
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Enum {
     pub name: String,
-    #[serde(rename = "$value", default)]
+    pub since: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    pub bitfield: bool,
+    #[serde(rename(serialize = "items", deserialize = "$value"), default)]
     pub items: Vec<EnumChild>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Arg {
     pub name: String,
 
     #[serde(rename = "type", default)]
     pub typ: String,
+    #[serde(default)]
     pub summary: String,
+    pub interface: Option<String>,
+    #[serde(rename = "allow-null", default, deserialize_with = "deserialize_flag")]
+    pub allow_null: bool,
+    #[serde(rename = "enum")]
+    pub enum_name: Option<String>,
+    pub description: Option<Description>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EventOrRequestField {
-    Description(String),
+    Description(Description),
     Arg(Arg),
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Event {
     pub name: String,
+    #[serde(rename = "type")]
+    pub typ: Option<String>,
+    pub since: Option<u32>,
+    #[serde(rename = "deprecated-since")]
+    pub deprecated_since: Option<u32>,
 
-    #[serde(rename = "$value", default)]
+    #[serde(rename(serialize = "items", deserialize = "$value"), default)]
     pub items: Vec<EventOrRequestField>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Request {
     pub name: String,
+    #[serde(rename = "type")]
+    pub typ: Option<String>,
+    pub since: Option<u32>,
+    #[serde(rename = "deprecated-since")]
+    pub deprecated_since: Option<u32>,
 
-    #[serde(rename = "$value", default)]
+    #[serde(rename(serialize = "items", deserialize = "$value"), default)]
     pub items: Vec<EventOrRequestField>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum InterfaceChild {
     Request(Request),
-    Description(String),
+    Description(Description),
     Event(Event),
     Enum(Enum),
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Interface {
     pub name: String,
-    pub version: String,
+    pub version: u32,
 
-    #[serde(rename = "$value", default)]
+    #[serde(rename(serialize = "items", deserialize = "$value"), default)]
     pub items: Vec<InterfaceChild>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ProtocolChild {
     Interface(Interface),
-    CopyRight(String),
+    Copyright(Copyright),
+    Description(Description),
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Protocol {
     pub name: String,
 
-    #[serde(rename = "$value", default)]
+    #[serde(rename(serialize = "items", deserialize = "$value"), default)]
     pub items: Vec<ProtocolChild>,
 }
 
-pub fn parse_wayland_protocol() -> Protocol {
+impl Protocol {
+    pub fn copyright(&self) -> Option<&Copyright> {
+        self.items.iter().find_map(|item| match item {
+            ProtocolChild::Copyright(copyright) => Some(copyright),
+            _ => None,
+        })
+    }
+
+    pub fn description(&self) -> Option<&Description> {
+        self.items.iter().find_map(|item| match item {
+            ProtocolChild::Description(description) => Some(description),
+            _ => None,
+        })
+    }
+
+    pub fn interfaces(&self) -> impl Iterator<Item = &Interface> {
+        self.items.iter().filter_map(|item| match item {
+            ProtocolChild::Interface(interface) => Some(interface),
+            _ => None,
+        })
+    }
+}
+
+impl Interface {
+    pub fn description(&self) -> Option<&Description> {
+        self.items.iter().find_map(|item| match item {
+            InterfaceChild::Description(description) => Some(description),
+            _ => None,
+        })
+    }
+
+    pub fn requests(&self) -> impl Iterator<Item = &Request> {
+        self.items.iter().filter_map(|item| match item {
+            InterfaceChild::Request(request) => Some(request),
+            _ => None,
+        })
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &Event> {
+        self.items.iter().filter_map(|item| match item {
+            InterfaceChild::Event(event) => Some(event),
+            _ => None,
+        })
+    }
+
+    pub fn request_opcode(&self, name: &str) -> Option<u16> {
+        let opcode = self.requests().position(|req| req.name == name)?;
+        Some(opcode as u16)
+    }
+
+    pub fn event_opcode(&self, name: &str) -> Option<u16> {
+        let opcode = self.events().position(|ev| ev.name == name)?;
+        Some(opcode as u16)
+    }
+
+    pub fn enums(&self) -> impl Iterator<Item = &Enum> {
+        self.items.iter().filter_map(|item| match item {
+            InterfaceChild::Enum(enumeration) => Some(enumeration),
+            _ => None,
+        })
+    }
+}
+
+impl Enum {
+    pub fn description(&self) -> Option<&Description> {
+        self.items.iter().find_map(|item| match item {
+            EnumChild::Description(description) => Some(description),
+            _ => None,
+        })
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.items.iter().filter_map(|item| match item {
+            EnumChild::Entry(entry) => Some(entry),
+            _ => None,
+        })
+    }
+}
+
+impl Entry {
+    pub fn parse_value(&self) -> Option<u32> {
+        let value = self.value.trim();
+        if value.starts_with("0x") || value.starts_with("0X") {
+            u32::from_str_radix(&value[2..], 16).ok()
+        } else {
+            value.parse().ok()
+        }
+    }
+}
+
+impl Arg {
+    pub fn signature(&self) -> String {
+        let code = match &self.typ[..] {
+            "int" => "i",
+            "uint" => "u",
+            "fixed" => "f",
+            "string" => "s",
+            "object" => "o",
+            // Without an interface the new_id is sent along with the interface
+            // name and version it is created with.
+            "new_id" if self.interface.is_none() => "sun",
+            "new_id" => "n",
+            "array" => "a",
+            "fd" => "h",
+            _ => "",
+        };
+        if self.allow_null {
+            format!("?{}", code)
+        } else {
+            code.to_string()
+        }
+    }
+}
+
+impl Event {
+    pub fn description(&self) -> Option<&Description> {
+        self.items.iter().find_map(|item| match item {
+            EventOrRequestField::Description(description) => Some(description),
+            _ => None,
+        })
+    }
+
+    pub fn args(&self) -> impl Iterator<Item = &Arg> {
+        self.items.iter().filter_map(|item| match item {
+            EventOrRequestField::Arg(arg) => Some(arg),
+            _ => None,
+        })
+    }
+
+    pub fn signature(&self) -> String {
+        self.args().map(Arg::signature).collect()
+    }
+
+    pub fn is_destructor(&self) -> bool {
+        self.typ.as_deref() == Some("destructor")
+    }
+}
+
+impl Request {
+    pub fn description(&self) -> Option<&Description> {
+        self.items.iter().find_map(|item| match item {
+            EventOrRequestField::Description(description) => Some(description),
+            _ => None,
+        })
+    }
+
+    pub fn args(&self) -> impl Iterator<Item = &Arg> {
+        self.items.iter().filter_map(|item| match item {
+            EventOrRequestField::Arg(arg) => Some(arg),
+            _ => None,
+        })
+    }
+
+    pub fn signature(&self) -> String {
+        self.args().map(Arg::signature).collect()
+    }
+
+    pub fn is_destructor(&self) -> bool {
+        self.typ.as_deref() == Some("destructor")
+    }
+}
+
+fn parse_protocol(contents: &str, file: Option<PathBuf>) -> Result<Protocol, ParseError> {
+    from_str(contents).map_err(|err| ParseError::from_xml(err, contents, file))
+}
+
+pub fn parse_protocol_from_str(contents: &str) -> Result<Protocol, ParseError> {
+    parse_protocol(contents, None)
+}
+
+pub fn parse_protocol_from_reader<R: Read>(mut reader: R) -> Result<Protocol, ParseError> {
+    let mut contents = String::new();
+    reader
+        .read_to_string(&mut contents)
+        .map_err(|err| ParseError::io(err, None))?;
+
+    parse_protocol(&contents, None)
+}
+
+pub fn parse_protocol_from_file<P: AsRef<Path>>(path: P) -> Result<Protocol, ParseError> {
+    let path = path.as_ref().to_path_buf();
+    let contents =
+        fs::read_to_string(&path).map_err(|err| ParseError::io(err, Some(path.clone())))?;
+
+    parse_protocol(&contents, Some(path))
+}
+
+pub fn parse_wayland_protocol() -> Result<Protocol, ParseError> {
     let contents = include_str!("../wayland.xml");
 
-    from_str(&contents).unwrap()
+    parse_protocol_from_str(contents)
 }
@@ -0,0 +1,372 @@
+use super::{
+    Arg, Description, Entry, Enum, EnumChild, Event, EventOrRequestField, Interface,
+    InterfaceChild, Protocol, ProtocolChild, Request,
+};
+use xml::escape::{escape_str_attribute, escape_str_pcdata};
+
+struct XmlWriter {
+    out: String,
+}
+
+impl XmlWriter {
+    fn indent(&mut self, depth: usize) {
+        for _ in 0..depth {
+            self.out.push_str("  ");
+        }
+    }
+
+    fn open(&mut self, depth: usize, tag: &str, attrs: &[(&str, String)], empty: bool) {
+        self.indent(depth);
+        self.out.push('<');
+        self.out.push_str(tag);
+        for (name, value) in attrs {
+            self.out
+                .push_str(&format!(" {}=\"{}\"", name, escape_str_attribute(value)));
+        }
+        self.out.push_str(if empty { "/>\n" } else { ">\n" });
+    }
+
+    fn close(&mut self, depth: usize, tag: &str) {
+        self.indent(depth);
+        self.out.push_str(&format!("</{}>\n", tag));
+    }
+
+    // The parser trims the text around a body, so the first line is the only one
+    // that needs indenting; the rest keep the indentation they were read with.
+    fn text(&mut self, depth: usize, tag: &str, attrs: &[(&str, String)], body: &str) {
+        if body.is_empty() {
+            self.open(depth, tag, attrs, true);
+            return;
+        }
+        self.open(depth, tag, attrs, false);
+        self.indent(depth + 1);
+        self.out.push_str(&escape_str_pcdata(body));
+        self.out.push('\n');
+        self.close(depth, tag);
+    }
+
+    fn description(&mut self, depth: usize, description: &Description) {
+        let attrs = [("summary", description.summary.clone())];
+        self.text(depth, "description", &attrs, &description.body);
+    }
+
+    fn arg(&mut self, depth: usize, arg: &Arg) {
+        let mut attrs = vec![("name", arg.name.clone()), ("type", arg.typ.clone())];
+        if let Some(interface) = &arg.interface {
+            attrs.push(("interface", interface.clone()));
+        }
+        if arg.allow_null {
+            attrs.push(("allow-null", String::from("true")));
+        }
+        if let Some(enum_name) = &arg.enum_name {
+            attrs.push(("enum", enum_name.clone()));
+        }
+        if !arg.summary.is_empty() {
+            attrs.push(("summary", arg.summary.clone()));
+        }
+        match &arg.description {
+            Some(description) => {
+                self.open(depth, "arg", &attrs, false);
+                self.description(depth + 1, description);
+                self.close(depth, "arg");
+            }
+            None => self.open(depth, "arg", &attrs, true),
+        }
+    }
+
+    fn message(
+        &mut self,
+        depth: usize,
+        tag: &str,
+        mut attrs: Vec<(&str, String)>,
+        items: &[EventOrRequestField],
+    ) {
+        attrs.retain(|(_, value)| !value.is_empty());
+        self.open(depth, tag, &attrs, items.is_empty());
+        if items.is_empty() {
+            return;
+        }
+        for item in items {
+            match item {
+                EventOrRequestField::Description(description) => {
+                    self.description(depth + 1, description)
+                }
+                EventOrRequestField::Arg(arg) => self.arg(depth + 1, arg),
+            }
+        }
+        self.close(depth, tag);
+    }
+
+    fn request(&mut self, depth: usize, req: &Request) {
+        let attrs = message_attrs(&req.name, &req.typ, req.since, req.deprecated_since);
+        self.message(depth, "request", attrs, &req.items);
+    }
+
+    fn event(&mut self, depth: usize, ev: &Event) {
+        let attrs = message_attrs(&ev.name, &ev.typ, ev.since, ev.deprecated_since);
+        self.message(depth, "event", attrs, &ev.items);
+    }
+
+    fn entry(&mut self, depth: usize, entry: &Entry) {
+        let mut attrs = vec![("name", entry.name.clone()), ("value", entry.value.clone())];
+        if !entry.summary.is_empty() {
+            attrs.push(("summary", entry.summary.clone()));
+        }
+        if let Some(since) = entry.since {
+            attrs.push(("since", since.to_string()));
+        }
+        if let Some(deprecated_since) = entry.deprecated_since {
+            attrs.push(("deprecated-since", deprecated_since.to_string()));
+        }
+        match &entry.description {
+            Some(description) => {
+                self.open(depth, "entry", &attrs, false);
+                self.description(depth + 1, description);
+                self.close(depth, "entry");
+            }
+            None => self.open(depth, "entry", &attrs, true),
+        }
+    }
+
+    fn enumeration(&mut self, depth: usize, enumeration: &Enum) {
+        let mut attrs = vec![("name", enumeration.name.clone())];
+        if let Some(since) = enumeration.since {
+            attrs.push(("since", since.to_string()));
+        }
+        if enumeration.bitfield {
+            attrs.push(("bitfield", String::from("true")));
+        }
+        self.open(depth, "enum", &attrs, enumeration.items.is_empty());
+        if enumeration.items.is_empty() {
+            return;
+        }
+        for item in &enumeration.items {
+            match item {
+                EnumChild::Description(description) => self.description(depth + 1, description),
+                EnumChild::Entry(entry) => self.entry(depth + 1, entry),
+            }
+        }
+        self.close(depth, "enum");
+    }
+
+    fn interface(&mut self, depth: usize, interface: &Interface) {
+        let attrs = [
+            ("name", interface.name.clone()),
+            ("version", interface.version.to_string()),
+        ];
+        self.open(depth, "interface", &attrs, false);
+        for (index, item) in interface.items.iter().enumerate() {
+            if index > 0 {
+                self.out.push('\n');
+            }
+            match item {
+                InterfaceChild::Description(description) => {
+                    self.description(depth + 1, description)
+                }
+                InterfaceChild::Request(req) => self.request(depth + 1, req),
+                InterfaceChild::Event(ev) => self.event(depth + 1, ev),
+                InterfaceChild::Enum(enumeration) => self.enumeration(depth + 1, enumeration),
+            }
+        }
+        self.close(depth, "interface");
+    }
+}
+
+fn message_attrs(
+    name: &str,
+    typ: &Option<String>,
+    since: Option<u32>,
+    deprecated_since: Option<u32>,
+) -> Vec<(&'static str, String)> {
+    vec![
+        ("name", name.to_string()),
+        ("type", typ.clone().unwrap_or_default()),
+        ("since", since.map(|v| v.to_string()).unwrap_or_default()),
+        (
+            "deprecated-since",
+            deprecated_since.map(|v| v.to_string()).unwrap_or_default(),
+        ),
+    ]
+}
+
+#[derive(Serialize)]
+struct ArgSchema<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    typ: &'a str,
+    signature: String,
+    interface: Option<&'a str>,
+    #[serde(rename = "enum")]
+    enum_name: Option<&'a str>,
+    allow_null: bool,
+}
+
+#[derive(Serialize)]
+struct MessageSchema<'a> {
+    name: &'a str,
+    opcode: usize,
+    since: u32,
+    destructor: bool,
+    signature: String,
+    args: Vec<ArgSchema<'a>>,
+}
+
+#[derive(Serialize)]
+struct EntrySchema<'a> {
+    name: &'a str,
+    value: Option<u32>,
+    since: u32,
+}
+
+#[derive(Serialize)]
+struct EnumSchema<'a> {
+    name: &'a str,
+    bitfield: bool,
+    entries: Vec<EntrySchema<'a>>,
+}
+
+#[derive(Serialize)]
+struct InterfaceSchema<'a> {
+    name: &'a str,
+    version: u32,
+    requests: Vec<MessageSchema<'a>>,
+    events: Vec<MessageSchema<'a>>,
+    enums: Vec<EnumSchema<'a>>,
+}
+
+#[derive(Serialize)]
+struct ProtocolSchema<'a> {
+    name: &'a str,
+    interfaces: Vec<InterfaceSchema<'a>>,
+}
+
+fn arg_schemas<'a, I: Iterator<Item = &'a Arg>>(args: I) -> Vec<ArgSchema<'a>> {
+    args.map(|arg| ArgSchema {
+        name: &arg.name,
+        typ: &arg.typ,
+        signature: arg.signature(),
+        interface: arg.interface.as_deref(),
+        enum_name: arg.enum_name.as_deref(),
+        allow_null: arg.allow_null,
+    })
+    .collect()
+}
+
+impl<'a> InterfaceSchema<'a> {
+    fn new(interface: &'a Interface) -> InterfaceSchema<'a> {
+        let requests = interface
+            .requests()
+            .enumerate()
+            .map(|(opcode, req)| MessageSchema {
+                name: &req.name,
+                opcode,
+                since: req.since.unwrap_or(1),
+                destructor: req.is_destructor(),
+                signature: req.signature(),
+                args: arg_schemas(req.args()),
+            });
+        let events = interface
+            .events()
+            .enumerate()
+            .map(|(opcode, ev)| MessageSchema {
+                name: &ev.name,
+                opcode,
+                since: ev.since.unwrap_or(1),
+                destructor: ev.is_destructor(),
+                signature: ev.signature(),
+                args: arg_schemas(ev.args()),
+            });
+        let enums = interface.enums().map(|enumeration| EnumSchema {
+            name: &enumeration.name,
+            bitfield: enumeration.bitfield,
+            entries: enumeration
+                .entries()
+                .map(|entry| EntrySchema {
+                    name: &entry.name,
+                    value: entry.parse_value(),
+                    since: entry.since.unwrap_or(1),
+                })
+                .collect(),
+        });
+        InterfaceSchema {
+            name: &interface.name,
+            version: interface.version,
+            requests: requests.collect(),
+            events: events.collect(),
+            enums: enums.collect(),
+        }
+    }
+}
+
+impl Protocol {
+    pub fn to_xml(&self) -> String {
+        let mut writer = XmlWriter {
+            out: String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"),
+        };
+        writer.open(0, "protocol", &[("name", self.name.clone())], false);
+        for item in &self.items {
+            writer.out.push('\n');
+            match item {
+                ProtocolChild::Copyright(copyright) => {
+                    writer.text(1, "copyright", &[], &copyright.body)
+                }
+                ProtocolChild::Description(description) => writer.description(1, description),
+                ProtocolChild::Interface(interface) => writer.interface(1, interface),
+            }
+        }
+        writer.out.push('\n');
+        writer.close(0, "protocol");
+        writer.out
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("protocol model is always serializable")
+    }
+
+    pub fn to_json_schema(&self) -> String {
+        let schema = ProtocolSchema {
+            name: &self.name,
+            interfaces: self.interfaces().map(InterfaceSchema::new).collect(),
+        };
+        serde_json::to_string_pretty(&schema).expect("protocol schema is always serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_protocol_from_str, parse_wayland_protocol};
+
+    #[test]
+    fn core_protocol_round_trips() {
+        let protocol = parse_wayland_protocol().unwrap();
+        let reparsed = parse_protocol_from_str(&protocol.to_xml()).unwrap();
+        assert_eq!(reparsed, protocol);
+    }
+
+    #[test]
+    fn attributes_and_escaping_round_trip() {
+        let protocol = parse_protocol_from_str(
+            r#"<protocol name="test">
+              <copyright>Copyright &amp; 2019 &lt;someone&gt;</copyright>
+              <interface name="a" version="3">
+                <description summary="quotes &quot;here&quot;">Text with &lt;tags&gt; &amp; more.
+                  Second line.</description>
+                <request name="destroy" type="destructor" since="2" deprecated-since="3"/>
+                <event name="e">
+                  <arg name="o" type="object" interface="a" allow-null="true" summary="s"/>
+                  <arg name="m" type="uint" enum="b"/>
+                </event>
+                <enum name="b" bitfield="true" since="2">
+                  <entry name="x" value="0x1" summary="first" since="2"/>
+                  <entry name="y" value="2">
+                    <description summary="y">Entry description.</description>
+                  </entry>
+                </enum>
+              </interface>
+            </protocol>"#,
+        )
+        .unwrap();
+        let reparsed = parse_protocol_from_str(&protocol.to_xml()).unwrap();
+        assert_eq!(reparsed, protocol);
+    }
+}
@@ -0,0 +1,96 @@
+use super::{
+    parse_protocol_from_file, validate_protocols, Arg, Diagnostic, Enum, Interface, ParseError,
+    Protocol, ProtocolChild,
+};
+use std::collections::HashMap;
+use std::path::Path;
+
+pub(crate) fn split_enum_reference(name: &str) -> (Option<&str>, &str) {
+    match name.find('.') {
+        Some(pos) => (Some(&name[..pos]), &name[pos + 1..]),
+        None => (None, name),
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ProtocolSet {
+    protocols: Vec<Protocol>,
+    interfaces: HashMap<String, (usize, usize)>,
+}
+
+impl ProtocolSet {
+    pub fn new() -> ProtocolSet {
+        ProtocolSet::default()
+    }
+
+    pub fn add(&mut self, protocol: Protocol) {
+        let protocol_index = self.protocols.len();
+        for (item_index, item) in protocol.items.iter().enumerate() {
+            if let ProtocolChild::Interface(interface) = item {
+                self.interfaces
+                    .entry(interface.name.clone())
+                    .or_insert((protocol_index, item_index));
+            }
+        }
+        self.protocols.push(protocol);
+    }
+
+    pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), ParseError> {
+        self.add(parse_protocol_from_file(path)?);
+        Ok(())
+    }
+
+    pub fn protocols(&self) -> &[Protocol] {
+        &self.protocols
+    }
+
+    pub fn interfaces(&self) -> impl Iterator<Item = &Interface> {
+        self.protocols
+            .iter()
+            .flat_map(|protocol| protocol.interfaces())
+    }
+
+    pub fn interface(&self, name: &str) -> Option<&Interface> {
+        let &(protocol_index, item_index) = self.interfaces.get(name)?;
+        match &self.protocols[protocol_index].items[item_index] {
+            ProtocolChild::Interface(interface) => Some(interface),
+            _ => None,
+        }
+    }
+
+    pub fn protocol_of(&self, interface: &str) -> Option<&Protocol> {
+        let &(protocol_index, _) = self.interfaces.get(interface)?;
+        Some(&self.protocols[protocol_index])
+    }
+
+    pub fn resolve_interface(&self, arg: &Arg) -> Option<&Interface> {
+        self.interface(arg.interface.as_ref()?)
+    }
+
+    pub fn resolve_enum<'a>(
+        &'a self,
+        interface: &'a Interface,
+        name: &str,
+    ) -> Option<(&'a Interface, &'a Enum)> {
+        let (interface, name) = match split_enum_reference(name) {
+            (Some(interface), name) => (self.interface(interface)?, name),
+            (None, name) => (interface, name),
+        };
+        let enumeration = interface
+            .enums()
+            .find(|enumeration| enumeration.name == name)?;
+        Some((interface, enumeration))
+    }
+
+    pub fn resolve_arg_enum<'a>(
+        &'a self,
+        interface: &'a Interface,
+        arg: &Arg,
+    ) -> Option<(&'a Interface, &'a Enum)> {
+        self.resolve_enum(interface, arg.enum_name.as_ref()?)
+    }
+
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        validate_protocols(&self.protocols)
+    }
+}
@@ -0,0 +1,434 @@
+use super::set::split_enum_reference;
+use super::{Arg, Enum, Interface, Protocol};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+const ARG_TYPES: [&str; 8] = [
+    "int", "uint", "fixed", "string", "object", "new_id", "array", "fd",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    DuplicateName(String),
+    UnknownArgType(String),
+    UnknownInterface(String),
+    UnknownEnum(String),
+    InterfaceOnNonObject,
+    EnumOnNonInteger,
+    NullOnNonNullable,
+    SinceAboveVersion {
+        since: u32,
+        version: u32,
+    },
+    InvalidEnumValue(String),
+    OverlappingEnumValues {
+        entry: String,
+        other: String,
+        value: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub protocol: String,
+    pub location: String,
+    pub kind: DiagnosticKind,
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiagnosticKind::DuplicateName(name) => write!(f, "duplicate name `{}`", name),
+            DiagnosticKind::UnknownArgType(typ) => write!(f, "unknown argument type `{}`", typ),
+            DiagnosticKind::UnknownInterface(name) => {
+                write!(f, "reference to unknown interface `{}`", name)
+            }
+            DiagnosticKind::UnknownEnum(name) => write!(f, "reference to unknown enum `{}`", name),
+            DiagnosticKind::InterfaceOnNonObject => {
+                write!(
+                    f,
+                    "`interface` is only allowed on object and new_id arguments"
+                )
+            }
+            DiagnosticKind::EnumOnNonInteger => {
+                write!(f, "`enum` is only allowed on int and uint arguments")
+            }
+            DiagnosticKind::NullOnNonNullable => write!(
+                f,
+                "`allow-null` is only allowed on object, new_id, string and array arguments"
+            ),
+            DiagnosticKind::SinceAboveVersion { since, version } => write!(
+                f,
+                "since {} is above the interface version {}",
+                since, version
+            ),
+            DiagnosticKind::InvalidEnumValue(value) => {
+                write!(f, "`{}` is not a valid enum value", value)
+            }
+            DiagnosticKind::OverlappingEnumValues {
+                entry,
+                other,
+                value,
+            } => write!(
+                f,
+                "entry `{}` has the same value {} as `{}` in a non-bitfield enum",
+                entry, value, other
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.protocol, self.location, self.kind)
+    }
+}
+
+impl Protocol {
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        validate_protocols(std::iter::once(self))
+    }
+}
+
+struct Validator<'a> {
+    interfaces: HashMap<&'a str, &'a Interface>,
+    diagnostics: Vec<Diagnostic>,
+    protocol: &'a str,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, location: String, kind: DiagnosticKind) {
+        self.diagnostics.push(Diagnostic {
+            protocol: self.protocol.to_string(),
+            location,
+            kind,
+        });
+    }
+
+    fn check_duplicates<I: Iterator<Item = &'a str>>(&mut self, scope: &str, names: I) {
+        let mut seen = HashSet::new();
+        for name in names {
+            if !seen.insert(name) {
+                self.report(
+                    format!("{}.{}", scope, name),
+                    DiagnosticKind::DuplicateName(name.to_string()),
+                );
+            }
+        }
+    }
+
+    fn check_since(&mut self, location: String, since: Option<u32>, version: u32) {
+        if let Some(since) = since {
+            if since > version {
+                self.report(
+                    location,
+                    DiagnosticKind::SinceAboveVersion { since, version },
+                );
+            }
+        }
+    }
+
+    fn find_enum(&self, interface: &'a Interface, name: &str) -> Option<&'a Enum> {
+        let (interface, name) = match split_enum_reference(name) {
+            (Some(interface), name) => (*self.interfaces.get(interface)?, name),
+            (None, name) => (interface, name),
+        };
+        interface
+            .enums()
+            .find(|enumeration| enumeration.name == name)
+    }
+
+    fn check_arg(&mut self, interface: &'a Interface, location: String, arg: &'a Arg) {
+        if !ARG_TYPES.contains(&&arg.typ[..]) {
+            self.report(
+                location.clone(),
+                DiagnosticKind::UnknownArgType(arg.typ.clone()),
+            );
+        }
+        if let Some(name) = &arg.interface {
+            if arg.typ != "object" && arg.typ != "new_id" {
+                self.report(location.clone(), DiagnosticKind::InterfaceOnNonObject);
+            }
+            if !self.interfaces.contains_key(&name[..]) {
+                self.report(
+                    location.clone(),
+                    DiagnosticKind::UnknownInterface(name.clone()),
+                );
+            }
+        }
+        if let Some(name) = &arg.enum_name {
+            if arg.typ != "int" && arg.typ != "uint" {
+                self.report(location.clone(), DiagnosticKind::EnumOnNonInteger);
+            }
+            if self.find_enum(interface, name).is_none() {
+                self.report(location.clone(), DiagnosticKind::UnknownEnum(name.clone()));
+            }
+        }
+        if arg.allow_null && !["object", "new_id", "string", "array"].contains(&&arg.typ[..]) {
+            self.report(location, DiagnosticKind::NullOnNonNullable);
+        }
+    }
+
+    fn check_enum(&mut self, interface: &'a Interface, enumeration: &'a Enum) {
+        let scope = format!("{}.{}", interface.name, enumeration.name);
+        self.check_since(scope.clone(), enumeration.since, interface.version);
+        self.check_duplicates(&scope, enumeration.entries().map(|entry| &entry.name[..]));
+
+        let mut values: HashMap<u32, &str> = HashMap::new();
+        for entry in enumeration.entries() {
+            let location = format!("{}.{}", scope, entry.name);
+            self.check_since(location.clone(), entry.since, interface.version);
+            match entry.parse_value() {
+                Some(value) => {
+                    if enumeration.bitfield {
+                        continue;
+                    }
+                    if let Some(other) = values.get(&value) {
+                        let kind = DiagnosticKind::OverlappingEnumValues {
+                            entry: entry.name.clone(),
+                            other: other.to_string(),
+                            value,
+                        };
+                        self.report(location, kind);
+                    } else {
+                        values.insert(value, &entry.name);
+                    }
+                }
+                None => self.report(
+                    location,
+                    DiagnosticKind::InvalidEnumValue(entry.value.clone()),
+                ),
+            }
+        }
+    }
+
+    fn check_interface(&mut self, interface: &'a Interface) {
+        let scope = &interface.name;
+        self.check_duplicates(scope, interface.requests().map(|req| &req.name[..]));
+        self.check_duplicates(scope, interface.events().map(|ev| &ev.name[..]));
+        self.check_duplicates(scope, interface.enums().map(|en| &en.name[..]));
+
+        for req in interface.requests() {
+            let location = format!("{}.{}", scope, req.name);
+            self.check_since(location.clone(), req.since, interface.version);
+            self.check_duplicates(&location, req.args().map(|arg| &arg.name[..]));
+            for arg in req.args() {
+                self.check_arg(interface, format!("{}.{}", location, arg.name), arg);
+            }
+        }
+        for ev in interface.events() {
+            let location = format!("{}.{}", scope, ev.name);
+            self.check_since(location.clone(), ev.since, interface.version);
+            self.check_duplicates(&location, ev.args().map(|arg| &arg.name[..]));
+            for arg in ev.args() {
+                self.check_arg(interface, format!("{}.{}", location, arg.name), arg);
+            }
+        }
+        for enumeration in interface.enums() {
+            self.check_enum(interface, enumeration);
+        }
+    }
+}
+
+pub fn validate_protocols<'a, I>(protocols: I) -> Vec<Diagnostic>
+where
+    I: IntoIterator<Item = &'a Protocol>,
+{
+    let protocols: Vec<&Protocol> = protocols.into_iter().collect();
+    let mut validator = Validator {
+        interfaces: HashMap::new(),
+        diagnostics: Vec::new(),
+        protocol: "",
+    };
+
+    for protocol in &protocols {
+        validator.protocol = &protocol.name;
+        for interface in protocol.interfaces() {
+            if validator
+                .interfaces
+                .insert(&interface.name, interface)
+                .is_some()
+            {
+                validator.report(
+                    interface.name.clone(),
+                    DiagnosticKind::DuplicateName(interface.name.clone()),
+                );
+            }
+        }
+    }
+    for protocol in &protocols {
+        validator.protocol = &protocol.name;
+        for interface in protocol.interfaces() {
+            validator.check_interface(interface);
+        }
+    }
+
+    validator.diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_protocol_from_str, parse_wayland_protocol};
+
+    fn protocol(name: &str, interfaces: &str) -> Protocol {
+        parse_protocol_from_str(&format!(
+            r#"<protocol name="{}">{}</protocol>"#,
+            name, interfaces
+        ))
+        .unwrap()
+    }
+
+    fn kinds(interfaces: &str) -> Vec<(String, DiagnosticKind)> {
+        protocol("test", interfaces)
+            .validate()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.location, diagnostic.kind))
+            .collect()
+    }
+
+    #[test]
+    fn wayland_protocol_is_valid() {
+        assert_eq!(parse_wayland_protocol().unwrap().validate(), vec![]);
+    }
+
+    #[test]
+    fn duplicate_names() {
+        let interfaces = r#"<interface name="a" version="1">
+            <request name="r"><arg name="x" type="int"/><arg name="x" type="int"/></request>
+            <request name="r"/>
+        </interface>
+        <interface name="a" version="1"/>"#;
+        assert_eq!(
+            kinds(interfaces),
+            vec![
+                (
+                    "a".to_string(),
+                    DiagnosticKind::DuplicateName("a".to_string())
+                ),
+                (
+                    "a.r".to_string(),
+                    DiagnosticKind::DuplicateName("r".to_string())
+                ),
+                (
+                    "a.r.x".to_string(),
+                    DiagnosticKind::DuplicateName("x".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_args() {
+        let interfaces = r#"<interface name="a" version="1">
+            <event name="e">
+                <arg name="t" type="double"/>
+                <arg name="i" type="object" interface="b"/>
+                <arg name="o" type="uint" interface="a"/>
+                <arg name="n" type="string" enum="c"/>
+                <arg name="u" type="uint" enum="b.c"/>
+                <arg name="f" type="fd" allow-null="true"/>
+            </event>
+        </interface>"#;
+        assert_eq!(
+            kinds(interfaces),
+            vec![
+                (
+                    "a.e.t".to_string(),
+                    DiagnosticKind::UnknownArgType("double".to_string())
+                ),
+                (
+                    "a.e.i".to_string(),
+                    DiagnosticKind::UnknownInterface("b".to_string())
+                ),
+                ("a.e.o".to_string(), DiagnosticKind::InterfaceOnNonObject),
+                ("a.e.n".to_string(), DiagnosticKind::EnumOnNonInteger),
+                (
+                    "a.e.n".to_string(),
+                    DiagnosticKind::UnknownEnum("c".to_string())
+                ),
+                (
+                    "a.e.u".to_string(),
+                    DiagnosticKind::UnknownEnum("b.c".to_string())
+                ),
+                ("a.e.f".to_string(), DiagnosticKind::NullOnNonNullable),
+            ]
+        );
+    }
+
+    #[test]
+    fn since_above_version() {
+        let interfaces = r#"<interface name="a" version="2">
+            <request name="r" since="2"/>
+            <event name="e" since="3"/>
+            <enum name="b" since="4"><entry name="x" value="0" since="5"/></enum>
+        </interface>"#;
+        let kind = |since| DiagnosticKind::SinceAboveVersion { since, version: 2 };
+        assert_eq!(
+            kinds(interfaces),
+            vec![
+                ("a.e".to_string(), kind(3)),
+                ("a.b".to_string(), kind(4)),
+                ("a.b.x".to_string(), kind(5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_enum_values() {
+        let interfaces = r#"<interface name="a" version="1">
+            <enum name="b">
+                <entry name="x" value="0x10"/>
+                <entry name="y" value="16"/>
+                <entry name="z" value="-1"/>
+            </enum>
+            <enum name="c" bitfield="true">
+                <entry name="x" value="1"/>
+                <entry name="y" value="1"/>
+            </enum>
+        </interface>"#;
+        assert_eq!(
+            kinds(interfaces),
+            vec![
+                (
+                    "a.b.y".to_string(),
+                    DiagnosticKind::OverlappingEnumValues {
+                        entry: "y".to_string(),
+                        other: "x".to_string(),
+                        value: 16,
+                    }
+                ),
+                (
+                    "a.b.z".to_string(),
+                    DiagnosticKind::InvalidEnumValue("-1".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn references_resolve_across_protocols() {
+        let extension = protocol(
+            "extension",
+            r#"<interface name="a" version="1">
+                <request name="r">
+                    <arg name="o" type="object" interface="wl_output"/>
+                    <arg name="t" type="int" enum="wl_output.transform"/>
+                </request>
+            </interface>"#,
+        );
+        assert_eq!(
+            extension
+                .validate()
+                .into_iter()
+                .map(|diagnostic| diagnostic.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                DiagnosticKind::UnknownInterface("wl_output".to_string()),
+                DiagnosticKind::UnknownEnum("wl_output.transform".to_string()),
+            ]
+        );
+        let wayland = parse_wayland_protocol().unwrap();
+        assert_eq!(validate_protocols(vec![&wayland, &extension]), vec![]);
+    }
+}